@@ -4,6 +4,11 @@ use rustc_version::{ version_meta, Channel };
 
 fn main()
 {
+	// Declare the cfg flags set below, so rustc does not warn about them. `tokio_unstable` is set by
+	// the user through RUSTFLAGS.
+	//
+	println!( "cargo::rustc-check-cfg=cfg(stable, beta, nightly, rustc_dev, tokio_unstable)" );
+
 	// Set cfg flags depending on release channel
 	//
 	match version_meta().unwrap().channel
@@ -13,6 +13,14 @@ async fn lib_function( exec: impl SpawnHandle<()> )
 		tracing::info!( "I can spawn from a library" );
 
 	}).expect( "spawn task" ).await;
+
+	// The name of the task will show up in the log output.
+	//
+	exec.spawn_named( "named_task", async
+	{
+		tracing::info!( "I can spawn named tasks" );
+
+	}).expect( "spawn task" ).await;
 }
 
 
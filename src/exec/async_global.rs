@@ -1,7 +1,9 @@
 use
 {
-	crate        :: { SpawnHandle, LocalSpawnHandle, JoinHandle                } ,
+	crate        :: { SpawnHandle, LocalSpawnHandle, JoinHandle, AbortHandle   } ,
 	futures_task :: { FutureObj, LocalFutureObj, Spawn, LocalSpawn, SpawnError } ,
+	futures_util :: { FutureExt                                                } ,
+	std          :: { panic::AssertUnwindSafe                                  } ,
 
 	async_global_executor as async_global,
 };
@@ -45,6 +47,20 @@ impl AsyncGlobal
 }
 
 
+
+#[ cfg(not( target_os = "unknown")) ]
+//
+#[ cfg_attr( nightly, doc(cfg(not( target_os = "unknown" ))) ) ]
+//
+impl crate::BlockOn for AsyncGlobal
+{
+	fn block_on<F: std::future::Future>( &self, future: F ) -> F::Output
+	{
+		AsyncGlobal::block_on( future )
+	}
+}
+
+
 #[ cfg( target_arch = "wasm32" ) ]
 //
 impl Spawn for AsyncGlobal
@@ -79,9 +95,10 @@ impl<Out: 'static + Send> SpawnHandle<Out> for AsyncGlobal
 {
 	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
 	{
-		let handle = async_global::spawn( future );
+		let (fut, a_handle) = AbortHandle::wrap( AssertUnwindSafe( future ).catch_unwind() );
+		let handle          = async_global::spawn( fut );
 
-		Ok( JoinHandle::async_global(handle) )
+		Ok( JoinHandle::async_global(handle, a_handle) )
 	}
 }
 
@@ -93,9 +110,10 @@ impl<Out: 'static + Send> SpawnHandle<Out> for AsyncGlobal
 {
 	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
 	{
-		let handle = async_global::spawn_local( future );
+		let (fut, a_handle) = AbortHandle::wrap( AssertUnwindSafe( future ).catch_unwind() );
+		let handle          = async_global::spawn_local( fut );
 
-		Ok( JoinHandle::async_global(handle) )
+		Ok( JoinHandle::async_global(handle, a_handle) )
 	}
 }
 
@@ -105,9 +123,10 @@ impl<Out: 'static> LocalSpawnHandle<Out> for AsyncGlobal
 {
 	fn spawn_handle_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
 	{
-		let handle = async_global::spawn_local( future );
+		let (fut, a_handle) = AbortHandle::wrap( AssertUnwindSafe( future ).catch_unwind() );
+		let handle          = async_global::spawn_local( fut );
 
-		Ok( JoinHandle::async_global(handle) )
+		Ok( JoinHandle::async_global(handle, a_handle) )
 	}
 }
 
@@ -180,7 +199,49 @@ impl crate::TokioIo for AsyncGlobal {}
 
 
 
-#[ cfg( feature = "timer" ) ]
+/// Uses `async_io::Timer`, which is driven by the same reactor as the IO of this executor.
+//
+#[ cfg(not( target_os = "unknown" )) ]
+//
+impl crate::Timer for AsyncGlobal
+{
+	fn sleep( &self, dur: std::time::Duration ) -> futures_core::future::BoxFuture<'static, ()>
+	{
+		Box::pin( crate::AsyncIoSleep::after( dur ) )
+	}
+
+
+	fn sleep_until( &self, deadline: std::time::Instant ) -> futures_core::future::BoxFuture<'static, ()>
+	{
+		Box::pin( crate::AsyncIoSleep::at( deadline ) )
+	}
+}
+
+
+
+#[ cfg(not( target_os = "unknown" )) ]
+//
+impl crate::UnboxedTimer for AsyncGlobal
+{
+	type Sleep = crate::AsyncIoSleep;
+
+	fn sleep_unboxed( &self, dur: std::time::Duration ) -> Self::Sleep
+	{
+		crate::AsyncIoSleep::after( dur )
+	}
+
+
+	fn sleep_until_unboxed( &self, deadline: std::time::Instant ) -> Self::Sleep
+	{
+		crate::AsyncIoSleep::at( deadline )
+	}
+}
+
+
+
+// On Wasm there is no async-io, so use futures-timer.
+//
+#[ cfg(all( target_os = "unknown", feature = "timer" )) ]
 //
 #[ cfg_attr( nightly, doc(cfg(all( feature = "timer", feature = "async_global" ))) ) ]
 //
@@ -0,0 +1,40 @@
+use
+{
+	std          :: { future::Future, pin::Pin, task::{ Context, Poll }, time::{ Duration, Instant } } ,
+	futures_util :: { FutureExt                                                                       } ,
+};
+
+
+/// The sleep future of [`AsyncStd`](crate::AsyncStd) and [`AsyncGlobal`](crate::AsyncGlobal), returned by
+/// [`UnboxedTimer::sleep_unboxed`](crate::UnboxedTimer::sleep_unboxed). It wraps an `async_io::Timer`,
+/// which resolves to the `Instant` it fired at, to resolve to `()` instead.
+//
+#[ derive( Debug ) ]
+//
+pub struct AsyncIoSleep( async_io::Timer );
+
+
+impl AsyncIoSleep
+{
+	pub(crate) fn after( dur: Duration ) -> Self
+	{
+		Self( async_io::Timer::after( dur ) )
+	}
+
+
+	pub(crate) fn at( deadline: Instant ) -> Self
+	{
+		Self( async_io::Timer::at( deadline ) )
+	}
+}
+
+
+impl Future for AsyncIoSleep
+{
+	type Output = ();
+
+	fn poll( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<()>
+	{
+		self.0.poll_unpin( cx ).map( drop )
+	}
+}
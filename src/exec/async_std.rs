@@ -1,8 +1,9 @@
 use
 {
-	crate        :: { SpawnHandle, LocalSpawnHandle, JoinHandle,               } ,
-	futures_task :: { FutureObj, LocalFutureObj, Spawn, LocalSpawn, SpawnError } ,
-	futures_util :: { future::abortable                                        } ,
+	crate        :: { SpawnHandle, LocalSpawnHandle, JoinHandle, AbortHandle, Named } ,
+	futures_task :: { FutureObj, LocalFutureObj, Spawn, LocalSpawn, SpawnError      } ,
+	futures_util :: { FutureExt                                                     } ,
+	std          :: { panic::AssertUnwindSafe                                       } ,
 
 	async_std_crate as async_std,
 };
@@ -44,6 +45,20 @@ impl AsyncStd
 
 
 
+#[ cfg(not( target_os = "unknown")) ]
+//
+#[ cfg_attr( nightly, doc(cfg(not( target_os = "unknown" ))) ) ]
+//
+impl crate::BlockOn for AsyncStd
+{
+	fn block_on<F: std::future::Future>( &self, future: F ) -> F::Output
+	{
+		AsyncStd::block_on( future )
+	}
+}
+
+
+
 #[ cfg( target_arch = "wasm32" ) ]
 //
 impl Spawn for AsyncStd
@@ -78,11 +93,22 @@ impl<Out: 'static + Send> SpawnHandle<Out> for AsyncStd
 {
 	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
 	{
-		let (fut, a_handle) = abortable( future );
+		let (fut, a_handle) = AbortHandle::wrap( AssertUnwindSafe( future ).catch_unwind() );
 		let handle          = async_std::task::spawn( fut );
 
 		Ok( JoinHandle::async_std(handle, a_handle) )
 	}
+
+
+	fn spawn_handle_obj_named( &self, future: FutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let (fut, a_handle) = AbortHandle::wrap( AssertUnwindSafe( Named::new( future, name ) ).catch_unwind() );
+
+		let handle = async_std::task::Builder::new().name( name.to_string() ).spawn( fut )
+			.map_err( |_| SpawnError::shutdown() )?;
+
+		Ok( JoinHandle::async_std(handle, a_handle) )
+	}
 }
 
 
@@ -95,12 +121,23 @@ impl<Out: 'static + Send> SpawnHandle<Out> for AsyncStd
 {
 	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
 	{
-		let (fut, a_handle) = abortable( future );
+		let (fut, a_handle) = AbortHandle::wrap( AssertUnwindSafe( future ).catch_unwind() );
 		let handle          = async_std::task::spawn_local( fut );
 
 		Ok( JoinHandle::async_std(handle, a_handle) )
 
 	}
+
+
+	fn spawn_handle_obj_named( &self, future: FutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let (fut, a_handle) = AbortHandle::wrap( AssertUnwindSafe( Named::new( future, name ) ).catch_unwind() );
+
+		let handle = async_std::task::Builder::new().name( name.to_string() ).local( fut )
+			.map_err( |_| SpawnError::shutdown() )?;
+
+		Ok( JoinHandle::async_std(handle, a_handle) )
+	}
 }
 
 
@@ -109,11 +146,22 @@ impl<Out: 'static> LocalSpawnHandle<Out> for AsyncStd
 {
 	fn spawn_handle_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
 	{
-		let (fut, a_handle) = abortable( future );
+		let (fut, a_handle) = AbortHandle::wrap( AssertUnwindSafe( future ).catch_unwind() );
 		let handle = async_std::task::spawn_local( fut );
 
 		Ok( JoinHandle::async_std(handle, a_handle))
 	}
+
+
+	fn spawn_handle_local_obj_named( &self, future: LocalFutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let (fut, a_handle) = AbortHandle::wrap( AssertUnwindSafe( Named::new( future, name ) ).catch_unwind() );
+
+		let handle = async_std::task::Builder::new().name( name.to_string() ).local( fut )
+			.map_err( |_| SpawnError::shutdown() )?;
+
+		Ok( JoinHandle::async_std(handle, a_handle) )
+	}
 }
 
 
@@ -181,13 +229,41 @@ impl crate::TokioIo for AsyncStd {}
 
 
 
+/// Uses `async_io::Timer`, which is what async-std uses for it's own sleep.
+//
 #[ cfg(not( target_arch = "wasm32" )) ]
 //
 impl crate::Timer for AsyncStd
 {
 	fn sleep( &self, dur: std::time::Duration ) -> futures_core::future::BoxFuture<'static, ()>
 	{
-		Box::pin( async_std::task::sleep(dur) )
+		Box::pin( crate::AsyncIoSleep::after( dur ) )
+	}
+
+
+	fn sleep_until( &self, deadline: std::time::Instant ) -> futures_core::future::BoxFuture<'static, ()>
+	{
+		Box::pin( crate::AsyncIoSleep::at( deadline ) )
+	}
+}
+
+
+
+#[ cfg(not( target_arch = "wasm32" )) ]
+//
+impl crate::UnboxedTimer for AsyncStd
+{
+	type Sleep = crate::AsyncIoSleep;
+
+	fn sleep_unboxed( &self, dur: std::time::Duration ) -> Self::Sleep
+	{
+		crate::AsyncIoSleep::after( dur )
+	}
+
+
+	fn sleep_until_unboxed( &self, deadline: std::time::Instant ) -> Self::Sleep
+	{
+		crate::AsyncIoSleep::at( deadline )
 	}
 }
 
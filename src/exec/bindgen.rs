@@ -1,9 +1,9 @@
 use
 {
-	crate                :: { SpawnHandle, LocalSpawnHandle, JoinHandle, YieldNow      } ,
-	wasm_bindgen_futures :: { spawn_local                                              } ,
-	futures_task         :: { FutureObj, LocalFutureObj, Spawn, LocalSpawn, SpawnError } ,
-	futures_util         :: { FutureExt                                                } ,
+	crate                :: { SpawnHandle, LocalSpawnHandle, JoinHandle, AbortHandle, YieldNow } ,
+	wasm_bindgen_futures :: { spawn_local                                                   } ,
+	futures_task         :: { FutureObj, LocalFutureObj, Spawn, LocalSpawn, SpawnError      } ,
+	futures_util         :: { FutureExt                                                     } ,
 };
 
 
@@ -58,10 +58,12 @@ impl<Out: 'static + Send> SpawnHandle<Out> for Bindgen
 {
 	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
 	{
-		let (fut, handle) = future.remote_handle();
-		spawn_local(fut);
+		let (task, a_handle) = AbortHandle::wrap( future );
+		let (fut , handle  ) = task.remote_handle();
 
-		Ok( JoinHandle::remote_handle(handle) )
+		spawn_local( fut );
+
+		Ok( JoinHandle::remote_handle(handle, a_handle) )
 	}
 }
 
@@ -71,10 +73,12 @@ impl<Out: 'static> LocalSpawnHandle<Out> for Bindgen
 {
 	fn spawn_handle_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
 	{
-		let (fut, handle) = future.remote_handle();
-		spawn_local(fut);
+		let (task, a_handle) = AbortHandle::wrap( future );
+		let (fut , handle  ) = task.remote_handle();
+
+		spawn_local( fut );
 
-		Ok( JoinHandle::remote_handle(handle) )
+		Ok( JoinHandle::remote_handle(handle, a_handle) )
 	}
 }
 
@@ -0,0 +1,380 @@
+use
+{
+	crate::iface :: { *                                                                      } ,
+	futures_task :: { SpawnError, FutureObj, LocalFutureObj, Spawn, LocalSpawn              } ,
+	futures_util :: { future::FutureExt                                                      } ,
+	std          :: { collections::BTreeMap, future::Future, pin::Pin                        } ,
+	std          :: { sync::{ Arc, Mutex, MutexGuard, PoisonError }, task::{ Context, Poll, Waker } } ,
+};
+
+
+/// An executor wrapper that limits the number of tasks spawned through it which are alive at the same time.
+///
+/// When the limit is reached, the spawn methods of the traits return an error, as they cannot block. Use
+/// [`spawn_handle_when_ready`](Bounded::spawn_handle_when_ready) to wait until a task finishes instead.
+///
+/// A task holds on to it's slot until the executor drops it's future, that is when it has completed or has
+/// been aborted. Clones of a `Bounded` share the same limit. Closures passed to [`SpawnBlocking`] are not
+/// counted.
+///
+/// ```
+/// use async_executors::{ Bounded, SpawnHandle };
+///
+/// async fn run( exec: impl SpawnHandle<()> )
+/// {
+///    let exec = Bounded::new( exec, 10 );
+///
+///    for _ in 0..100
+///    {
+///       // Waits while 10 tasks are running.
+///       //
+///       exec.spawn_handle_when_ready( async { /* ... */ } ).await.expect( "spawn" ).detach();
+///    }
+/// }
+/// ```
+//
+#[ derive( Debug, Clone ) ]
+//
+pub struct Bounded<E>
+{
+	exec     : E                ,
+	semaphore: Arc< Semaphore > ,
+}
+
+
+
+impl<E> Bounded<E>
+{
+	/// Wrap an executor, allowing at most `max_tasks` tasks to be alive at the same time.
+	///
+	/// # Panics
+	///
+	/// When `max_tasks` is zero, as nothing could be spawned.
+	//
+	pub fn new( exec: E, max_tasks: usize ) -> Self
+	{
+		assert!( max_tasks > 0, "Bounded requires max_tasks to be at least 1" );
+
+		Self{ exec, semaphore: Arc::new( Semaphore::new( max_tasks ) ) }
+	}
+
+
+	/// The number of tasks that can still be spawned before reaching the limit.
+	//
+	pub fn available( &self ) -> usize
+	{
+		self.semaphore.lock().available
+	}
+
+
+	/// Spawn a task once the number of alive tasks is below the limit. Returns an error if the wrapped
+	/// executor fails to spawn.
+	//
+	pub async fn spawn_handle_when_ready<Fut, Out>( &self, future: Fut ) -> Result<JoinHandle<Out>, SpawnError>
+
+		where E  : SpawnHandle<Out>                      ,
+		      Fut: Future<Output = Out> + Send + 'static ,
+		      Out: Send + 'static                        ,
+	{
+		let permit = Acquire::new( &self.semaphore ).await;
+
+		self.exec.spawn_handle_obj( FutureObj::new( permit.hold( future ).boxed() ) )
+	}
+
+
+	/// Spawn a `!Send` task once the number of alive tasks is below the limit. Returns an error if the wrapped
+	/// executor fails to spawn.
+	//
+	pub async fn spawn_handle_local_when_ready<Fut, Out>( &self, future: Fut ) -> Result<JoinHandle<Out>, SpawnError>
+
+		where E  : LocalSpawnHandle<Out>          ,
+		      Fut: Future<Output = Out> + 'static ,
+		      Out: 'static                        ,
+	{
+		let permit = Acquire::new( &self.semaphore ).await;
+
+		self.exec.spawn_handle_local_obj( LocalFutureObj::new( permit.hold( future ).boxed_local() ) )
+	}
+
+
+	/// Access the wrapped executor.
+	//
+	pub fn inner( &self ) -> &E
+	{
+		&self.exec
+	}
+
+
+	/// Unwrap the executor.
+	//
+	pub fn into_inner( self ) -> E
+	{
+		self.exec
+	}
+
+
+	// The `SpawnError` API only allows creating a shutdown error, so that's what we return when saturated.
+	//
+	fn try_acquire( &self ) -> Result<Permit, SpawnError>
+	{
+		self.semaphore.try_acquire().ok_or_else( SpawnError::shutdown )
+	}
+}
+
+
+
+/// Returns [`SpawnError::shutdown`] when the limit is reached.
+//
+impl<E> Spawn for Bounded<E> where E: Spawn
+{
+	fn spawn_obj( &self, future: FutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		let fut = self.try_acquire()?.hold( future );
+
+		self.exec.spawn_obj( FutureObj::new(fut.boxed()) )
+	}
+}
+
+
+
+/// Returns [`SpawnError::shutdown`] when the limit is reached.
+//
+impl<E> LocalSpawn for Bounded<E> where E: LocalSpawn
+{
+	fn spawn_local_obj( &self, future: LocalFutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		let fut = self.try_acquire()?.hold( future );
+
+		self.exec.spawn_local_obj( LocalFutureObj::new(fut.boxed_local()) )
+	}
+}
+
+
+
+/// Returns [`SpawnError::shutdown`] when the limit is reached.
+//
+impl<E, Out> SpawnHandle<Out> for Bounded<E> where E: SpawnHandle<Out>, Out: 'static + Send
+{
+	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let fut = self.try_acquire()?.hold( future );
+
+		self.exec.spawn_handle_obj( FutureObj::new(fut.boxed()) )
+	}
+
+
+	fn spawn_handle_obj_named( &self, future: FutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let fut = self.try_acquire()?.hold( future );
+
+		self.exec.spawn_handle_obj_named( FutureObj::new(fut.boxed()), name )
+	}
+}
+
+
+
+/// Returns [`SpawnError::shutdown`] when the limit is reached.
+//
+impl<E, Out> LocalSpawnHandle<Out> for Bounded<E> where E: LocalSpawnHandle<Out>, Out: 'static
+{
+	fn spawn_handle_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let fut = self.try_acquire()?.hold( future );
+
+		self.exec.spawn_handle_local_obj( LocalFutureObj::new(fut.boxed_local()) )
+	}
+
+
+	fn spawn_handle_local_obj_named( &self, future: LocalFutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let fut = self.try_acquire()?.hold( future );
+
+		self.exec.spawn_handle_local_obj_named( LocalFutureObj::new(fut.boxed_local()), name )
+	}
+}
+
+
+
+forward_traits!( Bounded );
+
+
+
+// A minimal async semaphore. Waiters are woken in the order they started waiting, but a task spawned
+// without waiting can still take a slot that was just released.
+//
+#[ derive( Debug ) ]
+//
+struct Semaphore
+{
+	state: Mutex<SemState>,
+}
+
+
+#[ derive( Debug ) ]
+//
+struct SemState
+{
+	available: usize                  ,
+	next_key : u64                    ,
+	waiters  : BTreeMap< u64, Waker > ,
+}
+
+
+impl Semaphore
+{
+	fn new( permits: usize ) -> Self
+	{
+		Self{ state: Mutex::new( SemState{ available: permits, next_key: 0, waiters: BTreeMap::new() } ) }
+	}
+
+
+	// No user code runs while holding the lock, so poisoning can be ignored.
+	//
+	fn lock( &self ) -> MutexGuard<'_, SemState>
+	{
+		self.state.lock().unwrap_or_else( PoisonError::into_inner )
+	}
+
+
+	fn try_acquire( self: &Arc<Self> ) -> Option<Permit>
+	{
+		let mut state = self.lock();
+
+		if state.available == 0
+		{
+			return None;
+		}
+
+		state.available -= 1;
+
+		Some( Permit{ semaphore: self.clone() } )
+	}
+
+
+	// Wake the first waiter, if any. It is removed from the queue, so it will register again if it
+	// doesn't obtain the permit.
+	//
+	fn notify( &self )
+	{
+		let waker = self.lock().waiters.pop_first().map( |(_, w)| w );
+
+		if let Some( w ) = waker { w.wake() }
+	}
+}
+
+
+
+// A slot in the semaphore, released when dropped.
+//
+#[ derive( Debug ) ]
+//
+struct Permit
+{
+	semaphore: Arc< Semaphore >,
+}
+
+
+impl Permit
+{
+	// Keep the permit until the future is dropped, whether it completed or not.
+	//
+	fn hold<F: Future>( self, future: F ) -> impl Future<Output = F::Output>
+	{
+		future.map( move |out| { drop( self ); out } )
+	}
+}
+
+
+impl Drop for Permit
+{
+	fn drop( &mut self )
+	{
+		self.semaphore.lock().available += 1;
+		self.semaphore.notify();
+	}
+}
+
+
+
+// Waits for a permit.
+//
+#[ derive( Debug ) ]
+//
+struct Acquire<'a>
+{
+	semaphore: &'a Arc< Semaphore > ,
+	key      : Option<u64>          ,
+}
+
+
+impl<'a> Acquire<'a>
+{
+	fn new( semaphore: &'a Arc< Semaphore > ) -> Self
+	{
+		Self{ semaphore, key: None }
+	}
+}
+
+
+impl Future for Acquire<'_>
+{
+	type Output = Permit;
+
+	fn poll( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Permit>
+	{
+		let mut state = self.semaphore.lock();
+
+		if state.available > 0
+		{
+			state.available -= 1;
+
+			if let Some( key ) = self.key.take()
+			{
+				state.waiters.remove( &key );
+			}
+
+			drop( state );
+
+			return Poll::Ready( Permit{ semaphore: self.semaphore.clone() } );
+		}
+
+		let key = match self.key
+		{
+			Some( key ) => key,
+
+			None =>
+			{
+				let key = state.next_key;
+				state.next_key += 1;
+				key
+			}
+		};
+
+		state.waiters.insert( key, cx.waker().clone() );
+		drop( state );
+
+		self.key = Some( key );
+
+		Poll::Pending
+	}
+}
+
+
+impl Drop for Acquire<'_>
+{
+	fn drop( &mut self )
+	{
+		let Some( key ) = self.key else { return };
+
+		let mut state = self.semaphore.lock();
+
+		// If we were removed from the queue, we got woken up for a permit we will not take,
+		// so pass it on to the next waiter.
+		//
+		if state.waiters.remove( &key ).is_none() && state.available > 0
+		{
+			drop( state );
+			self.semaphore.notify();
+		}
+	}
+}
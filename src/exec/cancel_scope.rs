@@ -0,0 +1,238 @@
+use
+{
+	crate::iface :: { *                                                         } ,
+	futures_task :: { SpawnError, FutureObj, LocalFutureObj, Spawn, LocalSpawn } ,
+	futures_util :: { future::FutureExt                                         } ,
+		std          :: { sync::{ Arc, Weak, Mutex, MutexGuard, PoisonError }       } ,
+};
+
+
+/// An executor wrapper that can cancel all tasks spawned through it at once, for example all the tasks
+/// that serve a connection.
+///
+/// Every task spawned through the scope is made abortable, also the ones spawned with [`Spawn`] and
+/// [`LocalSpawn`], which return no [`JoinHandle`]. Calling [`cancel`](CancelScope::cancel) aborts all
+/// of them and makes further spawns return [`SpawnError::shutdown`]. Tasks keep the [`TaskId`] the wrapped
+/// executor gives them, if any.
+///
+/// Scopes can be nested with [`child`](CancelScope::child). Cancelling a scope cancels all it's child scopes,
+/// but cancelling a child leaves the parent alone. Clones of a `CancelScope` are the same scope. Dropping a
+/// scope does not cancel it. Closures passed to [`SpawnBlocking`] cannot be cancelled.
+///
+/// ```
+/// use async_executors::CancelScope;
+/// use futures::task::{ Spawn, SpawnExt };
+///
+/// fn serve( exec: impl Spawn + Clone )
+/// {
+///    let connection = CancelScope::new( exec );
+///    let request    = connection.child();
+///
+///    connection.spawn( async { /* read */ } ).expect( "spawn" );
+///    request   .spawn( async { /* respond */ } ).expect( "spawn" );
+///
+///    // Aborts both tasks.
+///    //
+///    connection.cancel();
+///
+///    assert!( request.is_cancelled() );
+///    assert!( request.spawn( async {} ).is_err() );
+/// }
+/// ```
+//
+#[ derive( Debug, Clone ) ]
+//
+pub struct CancelScope<E>
+{
+	exec : E            ,
+	scope: Arc< Scope > ,
+}
+
+
+
+#[ derive( Debug, Default ) ]
+//
+struct Scope
+{
+	tracker : TaskTracker               ,
+	children: Mutex< Vec<Weak<Scope>> > ,
+}
+
+
+impl Scope
+{
+	// No user code runs while holding the lock, so poisoning can be ignored.
+	//
+	fn children( &self ) -> MutexGuard<'_, Vec<Weak<Scope>>>
+	{
+		self.children.lock().unwrap_or_else( PoisonError::into_inner )
+	}
+
+
+	// Close the tracker before taking the children, so a child created concurrently either
+	// ends up in the list or sees that we are cancelled.
+	//
+	fn cancel( &self )
+	{
+		self.tracker.cancel();
+
+		let children = std::mem::take( &mut *self.children() );
+
+		children.iter().filter_map( Weak::upgrade ).for_each( |child| child.cancel() );
+	}
+}
+
+
+
+impl<E> CancelScope<E>
+{
+	/// Create a new scope that spawns on `exec`.
+	//
+	pub fn new( exec: E ) -> Self
+	{
+		Self{ exec, scope: Arc::default() }
+	}
+
+
+	/// Create a child scope, which gets cancelled along with this scope. If this scope is already cancelled,
+	/// so is the child.
+	//
+	pub fn child( &self ) -> Self where E: Clone
+	{
+		let child = Self::new( self.exec.clone() );
+
+		{
+			let mut children = self.scope.children();
+
+			children.retain( |c| c.strong_count() > 0 );
+			children.push( Arc::downgrade( &child.scope ) );
+		}
+
+		if self.is_cancelled()
+		{
+			child.scope.cancel();
+		}
+
+		child
+	}
+
+
+	/// Abort all tasks spawned through this scope and it's child scopes. Further spawns will fail.
+	//
+	pub fn cancel( &self )
+	{
+		self.scope.cancel();
+	}
+
+
+	/// Whether this scope has been cancelled, either directly or through a parent.
+	//
+	pub fn is_cancelled( &self ) -> bool
+	{
+		self.scope.tracker.is_closed()
+	}
+
+
+	/// Access the wrapped executor.
+	//
+	pub fn inner( &self ) -> &E
+	{
+		&self.exec
+	}
+}
+
+
+
+/// Returns [`SpawnError::shutdown`] when the scope has been cancelled.
+//
+impl<E> Spawn for CancelScope<E> where E: Spawn
+{
+	fn spawn_obj( &self, future: FutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		let fut            = self.scope.tracker.track( future )?;
+		let (task, handle) = AbortHandle::wrap_keep_id( fut );
+
+		self.exec.spawn_obj( FutureObj::new( task.map( drop ).boxed() ) )?;
+		self.scope.tracker.register( handle );
+
+		Ok(())
+	}
+}
+
+
+
+/// Returns [`SpawnError::shutdown`] when the scope has been cancelled.
+//
+impl<E> LocalSpawn for CancelScope<E> where E: LocalSpawn
+{
+	fn spawn_local_obj( &self, future: LocalFutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		let fut            = self.scope.tracker.track( future )?;
+		let (task, handle) = AbortHandle::wrap_keep_id( fut );
+
+		self.exec.spawn_local_obj( LocalFutureObj::new( task.map( drop ).boxed_local() ) )?;
+		self.scope.tracker.register( handle );
+
+		Ok(())
+	}
+}
+
+
+
+/// Returns [`SpawnError::shutdown`] when the scope has been cancelled.
+//
+impl<E, Out> SpawnHandle<Out> for CancelScope<E> where E: SpawnHandle<Out>, Out: 'static + Send
+{
+	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let fut    = self.scope.tracker.track( future )?;
+		let handle = self.exec.spawn_handle_obj( FutureObj::new(fut.boxed()) )?;
+
+		self.scope.tracker.register( handle.abort_handle() );
+
+		Ok( handle )
+	}
+
+
+	fn spawn_handle_obj_named( &self, future: FutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let fut    = self.scope.tracker.track( future )?;
+		let handle = self.exec.spawn_handle_obj_named( FutureObj::new(fut.boxed()), name )?;
+
+		self.scope.tracker.register( handle.abort_handle() );
+
+		Ok( handle )
+	}
+}
+
+
+
+/// Returns [`SpawnError::shutdown`] when the scope has been cancelled.
+//
+impl<E, Out> LocalSpawnHandle<Out> for CancelScope<E> where E: LocalSpawnHandle<Out>, Out: 'static
+{
+	fn spawn_handle_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let fut    = self.scope.tracker.track( future )?;
+		let handle = self.exec.spawn_handle_local_obj( LocalFutureObj::new(fut.boxed_local()) )?;
+
+		self.scope.tracker.register( handle.abort_handle() );
+
+		Ok( handle )
+	}
+
+
+	fn spawn_handle_local_obj_named( &self, future: LocalFutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let fut    = self.scope.tracker.track( future )?;
+		let handle = self.exec.spawn_handle_local_obj_named( LocalFutureObj::new(fut.boxed_local()), name )?;
+
+		self.scope.tracker.register( handle.abort_handle() );
+
+		Ok( handle )
+	}
+}
+
+
+
+forward_traits!( CancelScope );
@@ -0,0 +1,258 @@
+use
+{
+	crate::iface :: { *                                                                  } ,
+	futures_task :: { SpawnError, FutureObj, LocalFutureObj, Spawn, LocalSpawn          } ,
+	futures_util :: { future::FutureExt                                                  } ,
+	pin_project  :: { pin_project                                                        } ,
+	std          :: { any::Any, fmt, future::Future, pin::Pin, sync::Arc                 } ,
+	std          :: { panic::{ catch_unwind, AssertUnwindSafe }                          } ,
+	std          :: { task::{ Context, Poll }                                            } ,
+};
+
+
+/// An executor wrapper that catches panics in the tasks spawned through it, so they behave the same
+/// on every executor.
+///
+/// Without it, a panic in a task unwinds the executor thread on some executors, while others catch it
+/// and only report it to whoever awaits the [`JoinHandle`]. With `CatchUnwind`:
+///
+/// - the executor never sees the panic,
+/// - the hook is called with the payload and the id and name of the task, from within the task,
+/// - tasks spawned with [`SpawnHandle`] or [`LocalSpawnHandle`] resolve their [`JoinHandle`] with
+///   [`JoinError::Panicked`] on [`try_join`](JoinHandle::try_join), and awaiting it resumes the panic
+///   in the awaiting task,
+/// - tasks spawned with [`Spawn`] or [`LocalSpawn`] just end.
+///
+/// For tasks with a `JoinHandle`, the task hands the payload to the `JoinHandle` and completes normally, so the
+/// panic is never resumed on the executor. The `JoinHandle` keeps the native id and abort of the task on the
+/// wrapped executor. If the hook panics, that panic is not caught.
+///
+/// ```
+/// use async_executors::{ CatchUnwind, SpawnHandle, SpawnHandleExt };
+///
+/// async fn run( exec: impl SpawnHandle<()> )
+/// {
+///    let exec = CatchUnwind::new( exec, |panic|
+///    {
+///       eprintln!( "task {:?} panicked: {:?}", panic.task_name, panic.message() );
+///    });
+///
+///    let handle = exec.spawn_handle( async { panic!( "boom" ) } ).expect( "spawn" );
+///
+///    assert!( handle.try_join().await.expect_err( "panicked" ).is_panic() );
+/// }
+/// ```
+//
+#[ derive( Debug, Clone ) ]
+//
+pub struct CatchUnwind<E>
+{
+	exec: E    ,
+	hook: Hook ,
+}
+
+
+
+/// A panic caught by [`CatchUnwind`], passed to it's hook.
+//
+#[ derive( Debug ) ]
+//
+pub struct TaskPanic<'a>
+{
+	/// The panic payload.
+	//
+	pub payload: &'a ( dyn Any + Send ),
+
+	/// The id of the task, if the executor provides one. See [`task_id`].
+	//
+	pub task_id: Option<TaskId>,
+
+	/// The name of the task, if it was spawned with a name.
+	//
+	pub task_name: Option< Arc<str> >,
+}
+
+
+impl TaskPanic<'_>
+{
+	/// The panic message if the task panicked with a `&str` or a `String`, which is what `panic!` generates.
+	//
+	pub fn message( &self ) -> Option<&str>
+	{
+		self.payload.downcast_ref::<&str>().copied()
+			.or_else( || self.payload.downcast_ref::<String>().map( String::as_str ) )
+	}
+}
+
+
+
+#[ derive( Clone ) ]
+//
+struct Hook( Arc< dyn Fn( &TaskPanic<'_> ) + Send + Sync > );
+
+
+impl fmt::Debug for Hook
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		f.write_str( "Hook" )
+	}
+}
+
+
+
+impl<E> CatchUnwind<E>
+{
+	/// Wrap an executor, calling `hook` for every panic caught in a task.
+	//
+	pub fn new( exec: E, hook: impl Fn( &TaskPanic<'_> ) + Send + Sync + 'static ) -> Self
+	{
+		Self{ exec, hook: Hook( Arc::new(hook) ) }
+	}
+
+
+	/// Access the wrapped executor.
+	//
+	pub fn inner( &self ) -> &E
+	{
+		&self.exec
+	}
+
+
+	/// Unwrap the executor.
+	//
+	pub fn into_inner( self ) -> E
+	{
+		self.exec
+	}
+
+
+	fn catch<F: Future>( &self, future: F, name: Option<&str> ) -> Caught<F>
+	{
+		Caught{ future, hook: self.hook.clone(), name: name.map( Arc::from ) }
+	}
+}
+
+
+
+// Catches a panic of the future and calls the hook. Resolves to the payload instead of unwinding.
+//
+#[ pin_project ]
+//
+#[ derive( Debug ) ]
+//
+struct Caught<F>
+{
+	#[pin] future: F                  ,
+	       hook  : Hook               ,
+	       name  : Option< Arc<str> > ,
+}
+
+
+impl<F: Future> Future for Caught<F>
+{
+	type Output = std::thread::Result<F::Output>;
+
+	fn poll( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Self::Output>
+	{
+		let this   = self.project();
+		let future = this.future;
+
+		match catch_unwind( AssertUnwindSafe( || future.poll( cx ) ) )
+		{
+			Ok( poll ) => poll.map( Ok ),
+
+			Err( payload ) =>
+			{
+				// We are still inside the task, so the executor has set it's id and name.
+				//
+				( this.hook.0 )( &TaskPanic
+				{
+					payload  : &*payload                              ,
+					task_id  : task_id()                              ,
+					task_name: this.name.clone().or_else( task_name ) ,
+				});
+
+				Poll::Ready( Err(payload) )
+			}
+		}
+	}
+}
+
+
+
+impl<E> Spawn for CatchUnwind<E> where E: Spawn
+{
+	fn spawn_obj( &self, future: FutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		let fut = self.catch( future, None ).map( drop );
+
+		self.exec.spawn_obj( FutureObj::new( fut.boxed() ) )
+	}
+}
+
+
+
+impl<E> LocalSpawn for CatchUnwind<E> where E: LocalSpawn
+{
+	fn spawn_local_obj( &self, future: LocalFutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		let fut = self.catch( future, None ).map( drop );
+
+		self.exec.spawn_local_obj( LocalFutureObj::new( fut.boxed_local() ) )
+	}
+}
+
+
+
+/// The task is spawned on the wrapped executor with a `JoinHandle<()>`, which is detached. The returned
+/// `JoinHandle` uses it's abort handle, so it keeps the native id.
+//
+impl<E, Out> SpawnHandle<Out> for CatchUnwind<E> where E: SpawnHandle<()>, Out: 'static + Send
+{
+	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let (fut, handle) = self.catch( future, None ).remote_handle();
+		let task          = self.exec.spawn_handle_obj( FutureObj::new( fut.boxed() ) )?;
+
+		Ok( JoinHandle::catch_unwind(handle, task) )
+	}
+
+
+	fn spawn_handle_obj_named( &self, future: FutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let (fut, handle) = self.catch( future, Some(name) ).remote_handle();
+		let task          = self.exec.spawn_handle_obj_named( FutureObj::new( fut.boxed() ), name )?;
+
+		Ok( JoinHandle::catch_unwind(handle, task) )
+	}
+}
+
+
+
+/// The task is spawned on the wrapped executor with a `JoinHandle<()>`, which is detached. The returned
+/// `JoinHandle` uses it's abort handle, so it keeps the native id.
+//
+impl<E, Out> LocalSpawnHandle<Out> for CatchUnwind<E> where E: LocalSpawnHandle<()>, Out: 'static
+{
+	fn spawn_handle_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let (fut, handle) = self.catch( future, None ).remote_handle();
+		let task          = self.exec.spawn_handle_local_obj( LocalFutureObj::new( fut.boxed_local() ) )?;
+
+		Ok( JoinHandle::catch_unwind(handle, task) )
+	}
+
+
+	fn spawn_handle_local_obj_named( &self, future: LocalFutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let (fut, handle) = self.catch( future, Some(name) ).remote_handle();
+		let task          = self.exec.spawn_handle_local_obj_named( LocalFutureObj::new( fut.boxed_local() ), name )?;
+
+		Ok( JoinHandle::catch_unwind(handle, task) )
+	}
+}
+
+
+
+forward_traits!( CatchUnwind );
@@ -0,0 +1,333 @@
+//! Provides DynExec, an executor that can be chosen at runtime.
+//
+#[ allow(unused_imports) ] // some imports are conditional on features
+//
+use
+{
+	crate        :: { SpawnHandle, LocalSpawnHandle, JoinHandle, YieldNow, YieldNowFut, BlockingHandle } ,
+	crate        :: { SpawnBlocking, Timer                                                           } ,
+	futures_task :: { FutureObj, LocalFutureObj, Spawn, LocalSpawn, SpawnError                        } ,
+};
+
+#[ cfg( feature = "tokio_tp"     ) ] use crate::TokioTp;
+#[ cfg( feature = "tokio_ct"     ) ] use crate::TokioCt;
+#[ cfg( feature = "async_std"    ) ] use crate::AsyncStd;
+#[ cfg( feature = "async_global" ) ] use crate::AsyncGlobal;
+#[ cfg( feature = "glommio"      ) ] use crate::GlommioCt;
+#[ cfg( feature = "threadpool"   ) ] use futures_executor::ThreadPool;
+#[ cfg( feature = "localpool"    ) ] use futures_executor::LocalSpawner;
+
+
+
+/// An executor that is chosen at runtime, for example from a command line flag. It has one variant
+/// per enabled backend and implements the traits from this crate by forwarding to the active variant,
+/// so code that uses it only gets compiled once.
+///
+/// Where the active executor lacks a capability:
+///
+/// - [`LocalSpawn`] and [`LocalSpawnHandle`] are not supported on [`TokioTp`](crate::TokioTp) and
+///   [`ThreadPool`](crate::ThreadPool), as these can't run `!Send` futures. [`SpawnError`] can only express
+///   that an executor is shut down, so that is the error they return, even though the executor is still
+///   running. Check [`can_spawn_local`](DynExec::can_spawn_local) up front to tell the two apart.
+/// - [`SpawnBlocking`](crate::SpawnBlocking) runs the closure on the bounded threadpool of the
+///   [_blocking_](https://docs.rs/blocking) crate for [`GlommioCt`](crate::GlommioCt), [`ThreadPool`](crate::ThreadPool)
+///   and [`LocalSpawner`](crate::LocalSpawner), as they don't have a threadpool for blocking tasks. It is not
+///   available on Wasm.
+/// - [`Timer`](crate::Timer) is implemented when every enabled variant has a timer. [`GlommioCt`](crate::GlommioCt)
+///   always has one, [`AsyncStd`](crate::AsyncStd) and [`AsyncGlobal`](crate::AsyncGlobal) have one on native
+///   targets, the tokio executors need `tokio_timer` or `timer` and the executors from the futures library need
+///   `timer`. Each variant uses it's own timer.
+/// - [`TokioIo`](crate::TokioIo) is implemented when one of the `tokio_io`, `async_global_tokio` or `async_std_tokio`
+///   features is enabled. Only the variants that implement `TokioIo` themselves will have a tokio reactor running.
+///
+/// Note that `DynExec` is neither `Send` nor `Sync` when one of the features `tokio_ct`, `glommio` or `localpool`
+/// is enabled, as those executors are `!Send`.
+///
+/// ```
+/// # #[ cfg(all( feature = "tokio_tp", feature = "async_std" )) ]
+/// # {
+/// use async_executors::{ AsyncStd, DynExec, SpawnHandle, SpawnHandleExt, TokioTp };
+///
+/// async fn lib_function( exec: impl SpawnHandle<u8> ) -> u8
+/// {
+///    exec.spawn_handle( async { 5 } ).expect( "spawn" ).await
+/// }
+///
+/// let use_tokio = std::env::args().any( |arg| arg == "--tokio" );
+///
+/// let out = if use_tokio
+/// {
+///    let tokio = TokioTp::new().expect( "create tokio threadpool" );
+///    tokio.block_on( lib_function( DynExec::from( tokio.clone() ) ) )
+/// }
+///
+/// else
+/// {
+///    AsyncStd::block_on( lib_function( DynExec::from( AsyncStd ) ) )
+/// };
+///
+/// assert_eq!( out, 5 );
+/// # }
+/// ```
+//
+#[ derive( Debug, Clone ) ]
+//
+#[ non_exhaustive ]
+//
+pub enum DynExec
+{
+	/// A [`TokioTp`](crate::TokioTp) executor.
+	//
+	#[ cfg( feature = "tokio_tp" ) ] TokioTp( TokioTp ),
+
+	/// A [`TokioCt`](crate::TokioCt) executor.
+	//
+	#[ cfg( feature = "tokio_ct" ) ] TokioCt( TokioCt ),
+
+	/// An [`AsyncStd`](crate::AsyncStd) executor.
+	//
+	#[ cfg( feature = "async_std" ) ] AsyncStd( AsyncStd ),
+
+	/// An [`AsyncGlobal`](crate::AsyncGlobal) executor.
+	//
+	#[ cfg( feature = "async_global" ) ] AsyncGlobal( AsyncGlobal ),
+
+	/// A [`GlommioCt`](crate::GlommioCt) executor.
+	//
+	#[ cfg( feature = "glommio" ) ] GlommioCt( GlommioCt ),
+
+	/// A [`ThreadPool`](crate::ThreadPool) from the futures library.
+	//
+	#[ cfg( feature = "threadpool" ) ] ThreadPool( ThreadPool ),
+
+	/// A [`LocalSpawner`](crate::LocalSpawner) from the futures library.
+	//
+	#[ cfg( feature = "localpool" ) ] LocalSpawner( LocalSpawner ),
+}
+
+
+
+// Forward to the active variant. All variants must support the operation.
+//
+macro_rules! dispatch
+{
+	( $self:expr, $exec:ident => $body:expr ) =>
+	{
+		match $self
+		{
+			#[ cfg( feature = "tokio_tp"     ) ] DynExec::TokioTp     ( $exec ) => $body ,
+			#[ cfg( feature = "tokio_ct"     ) ] DynExec::TokioCt     ( $exec ) => $body ,
+			#[ cfg( feature = "async_std"    ) ] DynExec::AsyncStd    ( $exec ) => $body ,
+			#[ cfg( feature = "async_global" ) ] DynExec::AsyncGlobal ( $exec ) => $body ,
+			#[ cfg( feature = "glommio"      ) ] DynExec::GlommioCt   ( $exec ) => $body ,
+			#[ cfg( feature = "threadpool"   ) ] DynExec::ThreadPool  ( $exec ) => $body ,
+			#[ cfg( feature = "localpool"    ) ] DynExec::LocalSpawner( $exec ) => $body ,
+		}
+	};
+}
+
+
+
+// Forward to the active variant if it can spawn `!Send` futures, otherwise return an error.
+//
+macro_rules! dispatch_local
+{
+	( $self:expr, $exec:ident => $body:expr ) =>
+	{
+		match $self
+		{
+			#[ cfg( feature = "tokio_ct"     ) ] DynExec::TokioCt     ( $exec ) => $body ,
+			#[ cfg( feature = "async_std"    ) ] DynExec::AsyncStd    ( $exec ) => $body ,
+			#[ cfg( feature = "async_global" ) ] DynExec::AsyncGlobal ( $exec ) => $body ,
+			#[ cfg( feature = "glommio"      ) ] DynExec::GlommioCt   ( $exec ) => $body ,
+			#[ cfg( feature = "localpool"    ) ] DynExec::LocalSpawner( $exec ) => $body ,
+
+			#[ cfg( feature = "tokio_tp"     ) ] DynExec::TokioTp   (_) => Err( SpawnError::shutdown() ) ,
+			#[ cfg( feature = "threadpool"   ) ] DynExec::ThreadPool(_) => Err( SpawnError::shutdown() ) ,
+		}
+	};
+}
+
+
+
+impl DynExec
+{
+	/// Whether the active executor can spawn `!Send` futures through [`LocalSpawn`] and [`LocalSpawnHandle`].
+	/// This is false for [`TokioTp`](crate::TokioTp) and [`ThreadPool`](crate::ThreadPool).
+	//
+	// Without those features, only the last arm is left.
+	//
+	#[ allow( clippy::match_single_binding ) ]
+	//
+	pub fn can_spawn_local( &self ) -> bool
+	{
+		match self
+		{
+			#[ cfg( feature = "tokio_tp"   ) ] DynExec::TokioTp   (_) => false ,
+			#[ cfg( feature = "threadpool" ) ] DynExec::ThreadPool(_) => false ,
+
+			#[ allow( unreachable_patterns ) ]
+			//
+			_ => true,
+		}
+	}
+}
+
+
+
+#[ cfg( feature = "tokio_tp"     ) ] impl From<TokioTp     > for DynExec { fn from( e: TokioTp      ) -> Self { Self::TokioTp     (e) } }
+#[ cfg( feature = "tokio_ct"     ) ] impl From<TokioCt     > for DynExec { fn from( e: TokioCt      ) -> Self { Self::TokioCt     (e) } }
+#[ cfg( feature = "async_std"    ) ] impl From<AsyncStd    > for DynExec { fn from( e: AsyncStd     ) -> Self { Self::AsyncStd    (e) } }
+#[ cfg( feature = "async_global" ) ] impl From<AsyncGlobal > for DynExec { fn from( e: AsyncGlobal  ) -> Self { Self::AsyncGlobal (e) } }
+#[ cfg( feature = "glommio"      ) ] impl From<GlommioCt   > for DynExec { fn from( e: GlommioCt    ) -> Self { Self::GlommioCt   (e) } }
+#[ cfg( feature = "threadpool"   ) ] impl From<ThreadPool  > for DynExec { fn from( e: ThreadPool   ) -> Self { Self::ThreadPool  (e) } }
+#[ cfg( feature = "localpool"    ) ] impl From<LocalSpawner> for DynExec { fn from( e: LocalSpawner ) -> Self { Self::LocalSpawner(e) } }
+
+
+
+impl Spawn for DynExec
+{
+	fn spawn_obj( &self, future: FutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		dispatch!( self, exec => exec.spawn_obj( future ) )
+	}
+}
+
+
+
+// The arguments are unused when only executors that can't spawn `!Send` futures are enabled.
+//
+#[ allow(unused_variables) ]
+//
+impl LocalSpawn for DynExec
+{
+	/// Returns [`SpawnError::shutdown`] on executors that can't spawn `!Send` futures, see
+	/// [`DynExec::can_spawn_local`].
+	//
+	fn spawn_local_obj( &self, future: LocalFutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		dispatch_local!( self, exec => exec.spawn_local_obj( future ) )
+	}
+}
+
+
+
+impl<Out: 'static + Send> SpawnHandle<Out> for DynExec
+{
+	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		dispatch!( self, exec => exec.spawn_handle_obj( future ) )
+	}
+
+
+	fn spawn_handle_obj_named( &self, future: FutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		dispatch!( self, exec => exec.spawn_handle_obj_named( future, name ) )
+	}
+}
+
+
+
+// The arguments are unused when only executors that can't spawn `!Send` futures are enabled.
+//
+#[ allow(unused_variables) ]
+//
+impl<Out: 'static> LocalSpawnHandle<Out> for DynExec
+{
+	/// Returns [`SpawnError::shutdown`] on executors that can't spawn `!Send` futures, see
+	/// [`DynExec::can_spawn_local`].
+	//
+	fn spawn_handle_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		dispatch_local!( self, exec => exec.spawn_handle_local_obj( future ) )
+	}
+
+
+	/// Returns [`SpawnError::shutdown`] on executors that can't spawn `!Send` futures, see
+	/// [`DynExec::can_spawn_local`].
+	//
+	fn spawn_handle_local_obj_named( &self, future: LocalFutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		dispatch_local!( self, exec => exec.spawn_handle_local_obj_named( future, name ) )
+	}
+}
+
+
+
+impl YieldNow for DynExec
+{
+	fn yield_now( &self ) -> YieldNowFut
+	{
+		dispatch!( self, exec => exec.yield_now() )
+	}
+}
+
+
+
+// Only when every enabled variant has a timer, see the cfgs on the Timer impls of the executors.
+//
+#[ cfg(all
+(
+	any( feature = "timer", feature = "tokio_timer", not(any( feature = "tokio_tp", feature = "tokio_ct" )) ),
+	any( feature = "timer", not(any( feature = "threadpool", feature = "localpool" ))                       ),
+	any( feature = "timer", not( feature = "async_std"    ), not( target_arch = "wasm32"  )                 ),
+	any( feature = "timer", not( feature = "async_global" ), not( target_os   = "unknown" )                 ),
+)) ]
+//
+impl Timer for DynExec
+{
+	fn sleep( &self, dur: std::time::Duration ) -> futures_core::future::BoxFuture<'static, ()>
+	{
+		dispatch!( self, exec => exec.sleep( dur ) )
+	}
+
+
+	fn sleep_until( &self, deadline: std::time::Instant ) -> futures_core::future::BoxFuture<'static, ()>
+	{
+		dispatch!( self, exec => exec.sleep_until( deadline ) )
+	}
+
+
+	fn current_time( &self ) -> Option<std::time::Instant>
+	{
+		dispatch!( self, exec => exec.current_time() )
+	}
+}
+
+
+
+#[ cfg(any( feature = "tokio_io", feature = "async_global_tokio", feature = "async_std_tokio" )) ]
+//
+#[ cfg_attr( nightly, doc(cfg(any( feature = "tokio_io", feature = "async_global_tokio", feature = "async_std_tokio" ))) ) ]
+//
+impl crate::TokioIo for DynExec {}
+
+
+
+#[ cfg(not( target_arch = "wasm32" )) ]
+//
+impl<R: Send + 'static> SpawnBlocking<R> for DynExec
+{
+	fn spawn_blocking<F>( &self, f: F ) -> BlockingHandle<R>
+
+		where F: FnOnce() -> R + Send + 'static ,
+	{
+		match self
+		{
+			#[ cfg( feature = "tokio_tp"     ) ] DynExec::TokioTp    ( exec ) => exec.spawn_blocking( f ) ,
+			#[ cfg( feature = "tokio_ct"     ) ] DynExec::TokioCt    ( exec ) => exec.spawn_blocking( f ) ,
+			#[ cfg( feature = "async_std"    ) ] DynExec::AsyncStd   ( exec ) => exec.spawn_blocking( f ) ,
+			#[ cfg( feature = "async_global" ) ] DynExec::AsyncGlobal( exec ) => exec.spawn_blocking( f ) ,
+
+			#[ cfg( feature = "glommio"      ) ] DynExec::GlommioCt   (_) => BlockingHandle::thread( f ) ,
+			#[ cfg( feature = "threadpool"   ) ] DynExec::ThreadPool  (_) => BlockingHandle::thread( f ) ,
+			#[ cfg( feature = "localpool"    ) ] DynExec::LocalSpawner(_) => BlockingHandle::thread( f ) ,
+		}
+	}
+
+
+	fn spawn_blocking_dyn( &self, f: Box< dyn FnOnce()->R + Send > ) -> BlockingHandle<R>
+	{
+		self.spawn_blocking( f )
+	}
+}
@@ -0,0 +1,126 @@
+// Implements the traits of this crate which a wrapper around an executor passes through unchanged. The
+// wrapper must be generic over the executor, called `E`, and store it in a field called `exec`.
+//
+// `forward_traits!( Wrapper )` forwards all of them. Wrappers which implement some of these themselves
+// list the ones to forward instead, eg. `forward_traits!( Wrapper: BlockOn, YieldNow )`.
+//
+macro_rules! forward_traits
+{
+	( $wrapper:ident ) =>
+	{
+		forward_traits!( $wrapper: BlockOn, Shutdown, Timer, UnboxedTimer, YieldNow, TokioIo, SpawnBlocking );
+	};
+
+
+	( $wrapper:ident: $( $trait:ident ),+ $(,)? ) =>
+	{
+		$( forward_traits!( @impl $trait $wrapper ); )+
+	};
+
+
+	( @impl BlockOn $wrapper:ident ) =>
+	{
+		impl<E> $crate::BlockOn for $wrapper<E> where E: $crate::BlockOn
+		{
+			fn block_on<F: std::future::Future>( &self, future: F ) -> F::Output
+			{
+				self.exec.block_on( future )
+			}
+		}
+	};
+
+
+	( @impl Shutdown $wrapper:ident ) =>
+	{
+		impl<E> $crate::Shutdown for $wrapper<E> where E: $crate::Shutdown
+		{
+			fn shutdown( &self, timeout: std::time::Duration ) -> futures_core::future::BoxFuture<'static, $crate::ShutdownReport>
+			{
+				self.exec.shutdown( timeout )
+			}
+		}
+	};
+
+
+	( @impl Timer $wrapper:ident ) =>
+	{
+		impl<E> $crate::Timer for $wrapper<E> where E: $crate::Timer
+		{
+			fn sleep( &self, dur: std::time::Duration ) -> futures_core::future::BoxFuture<'static, ()>
+			{
+				self.exec.sleep( dur )
+			}
+
+
+			fn sleep_until( &self, deadline: std::time::Instant ) -> futures_core::future::BoxFuture<'static, ()>
+			{
+				self.exec.sleep_until( deadline )
+			}
+
+
+			fn current_time( &self ) -> Option<std::time::Instant>
+			{
+				self.exec.current_time()
+			}
+		}
+	};
+
+
+	( @impl UnboxedTimer $wrapper:ident ) =>
+	{
+		impl<E> $crate::UnboxedTimer for $wrapper<E> where E: $crate::UnboxedTimer
+		{
+			type Sleep = E::Sleep;
+
+			fn sleep_unboxed( &self, dur: std::time::Duration ) -> Self::Sleep
+			{
+				self.exec.sleep_unboxed( dur )
+			}
+
+
+			fn sleep_until_unboxed( &self, deadline: std::time::Instant ) -> Self::Sleep
+			{
+				self.exec.sleep_until_unboxed( deadline )
+			}
+		}
+	};
+
+
+	( @impl YieldNow $wrapper:ident ) =>
+	{
+		impl<E> $crate::YieldNow for $wrapper<E> where E: $crate::YieldNow
+		{
+			fn yield_now( &self ) -> $crate::YieldNowFut
+			{
+				self.exec.yield_now()
+			}
+		}
+	};
+
+
+	( @impl TokioIo $wrapper:ident ) =>
+	{
+		impl<E> $crate::TokioIo for $wrapper<E> where E: $crate::TokioIo {}
+	};
+
+
+	( @impl SpawnBlocking $wrapper:ident ) =>
+	{
+		impl<E, R> $crate::SpawnBlocking<R> for $wrapper<E> where E: $crate::SpawnBlocking<R>, R: Send + 'static
+		{
+			fn spawn_blocking<F>( &self, f: F ) -> $crate::BlockingHandle<R>
+
+				where F   : FnOnce() -> R + Send + 'static ,
+				      Self: Sized                          ,
+			{
+				self.exec.spawn_blocking( f )
+			}
+
+
+			fn spawn_blocking_dyn( &self, f: Box< dyn FnOnce()->R + Send > ) -> $crate::BlockingHandle<R>
+			{
+				self.exec.spawn_blocking_dyn( f )
+			}
+		}
+	};
+}
@@ -1,10 +1,13 @@
 use
 {
-	crate         :: { LocalSpawnHandle, SpawnHandle, JoinHandle, YieldNow     } ,
-	std           :: { future::Future, rc::Rc                                  } ,
-	futures_task  :: { FutureObj, LocalSpawn,  Spawn, SpawnError               } ,
-	futures_util  :: { FutureExt, task::LocalSpawnExt, future::LocalFutureObj  } ,
-	glommio_crate :: { LocalExecutor, LocalExecutorBuilder, GlommioError       } ,
+	crate           :: { LocalSpawnHandle, SpawnHandle, JoinHandle, AbortHandle, YieldNow } ,
+	std             :: { future::Future, rc::Rc, cell::Cell, time::{ Duration, Instant }   } ,
+	std             :: { pin::Pin, task::{ Context, Poll }, fmt                           } ,
+	futures_task    :: { FutureObj, LocalSpawn,  Spawn, SpawnError                        } ,
+	futures_util    :: { FutureExt, future::LocalFutureObj                                } ,
+	futures_core    :: { future::BoxFuture                                                } ,
+	glommio_crate   :: { LocalExecutor, LocalExecutorBuilder, GlommioError, timer::Timer  } ,
+	send_wrapper    :: { SendWrapper                                                      } ,
 };
 
 
@@ -23,9 +26,12 @@ use
 /// When it comes to YieldNow, glommio does not yield unless the task has been running for
 /// some time. The glommio method is actually called `yield_if_needed`.
 ///
-/// # Panics
+/// Tasks can only be spawned from within [`block_on`](GlommioCt::block_on). Outside of it, spawning
+/// returns [`SpawnError::shutdown`].
 ///
-/// Calling spawn from outside [`block_on`](GlommioCt::block_on) will panic.
+/// [`Timer`](crate::Timer) is always implemented with the io_uring based timer of glommio. Sleeps
+/// have to be polled on the thread they were created on, from within [`block_on`](GlommioCt::block_on),
+/// otherwise they panic unless their deadline has passed already.
 //
 #[ derive(Debug, Clone) ]
 //
@@ -33,7 +39,12 @@ use
 //
 pub struct GlommioCt
 {
-	exec: Rc<LocalExecutor>,
+	exec   : Rc<LocalExecutor> ,
+
+	// Whether block_on is running. Glommio panics when spawning without a running executor
+	// and doesn't expose a way to check.
+	//
+	running: Rc< Cell<bool> >  ,
 }
 
 
@@ -45,7 +56,7 @@ impl GlommioCt
 	{
 		let exec = Rc::new( builder.make()? );
 
-		Ok( Self{ exec } )
+		Ok( Self{ exec, running: Rc::new( Cell::new(false) ) } )
 	}
 
 
@@ -56,17 +67,57 @@ impl GlommioCt
 	//
 	pub fn block_on<F: Future>( &self, future: F ) -> F::Output
 	{
+		let _running = Running::enter( &self.running );
+
 		self.exec.run( future )
 	}
 }
 
 
 
+// Marks the executor as running until dropped, also when block_on unwinds.
+//
+struct Running<'a>
+{
+	running: &'a Cell<bool>,
+	prev   : bool          ,
+}
+
+
+impl<'a> Running<'a>
+{
+	fn enter( running: &'a Cell<bool> ) -> Self
+	{
+		Self{ prev: running.replace( true ), running }
+	}
+}
+
+
+impl Drop for Running<'_>
+{
+	fn drop( &mut self )
+	{
+		self.running.set( self.prev );
+	}
+}
+
+
+
+impl crate::BlockOn for GlommioCt
+{
+	fn block_on<F: Future>( &self, future: F ) -> F::Output
+	{
+		GlommioCt::block_on( self, future )
+	}
+}
+
+
+
 impl LocalSpawn for GlommioCt
 {
 	fn spawn_local_obj( &self, future: LocalFutureObj<'static, ()> ) -> Result<(), SpawnError>
 	{
-		glommio_crate::spawn_local( future ).detach();
+		self.spawn_handle_local_obj( future )?.detach();
 		Ok(())
 	}
 }
@@ -79,11 +130,17 @@ impl<Out: 'static> LocalSpawnHandle<Out> for GlommioCt
 
 		-> Result<JoinHandle<Out>, SpawnError>
 	{
-		let (remote, handle) = future.remote_handle();
+		if !self.running.get()
+		{
+			return Err( SpawnError::shutdown() );
+		}
+
+		let (task  , a_handle) = AbortHandle::wrap( future );
+		let (remote, handle  ) = task.remote_handle();
 
 		glommio_crate::spawn_local( remote ).detach();
 
-		Ok( JoinHandle::remote_handle(handle) )
+		Ok( JoinHandle::remote_handle(handle, a_handle) )
 	}
 }
 
@@ -93,7 +150,7 @@ impl Spawn for GlommioCt
 {
 	fn spawn_obj( &self, future: FutureObj<'static, ()> ) -> Result<(), SpawnError>
 	{
-		self.spawn_local( future )
+		self.spawn_local_obj( future.into() )
 	}
 }
 
@@ -103,27 +160,124 @@ impl<Out: Send + 'static> SpawnHandle<Out> for GlommioCt
 {
 	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
 	{
-		let (remote, handle) = future.remote_handle();
+		self.spawn_handle_local_obj( future.into() )
+	}
+}
+
 
-		glommio_crate::spawn_local( remote ).detach();
 
-		Ok( JoinHandle::remote_handle(handle) )
+/// Uses `glommio::timer::Timer`.
+//
+impl crate::Timer for GlommioCt
+{
+	fn sleep( &self, dur: Duration ) -> BoxFuture<'static, ()>
+	{
+		crate::UnboxedTimer::sleep_unboxed( self, dur ).boxed()
+	}
+
+
+	fn sleep_until( &self, deadline: Instant ) -> BoxFuture<'static, ()>
+	{
+		crate::UnboxedTimer::sleep_until_unboxed( self, deadline ).boxed()
 	}
 }
 
 
 
-#[ cfg( feature = "timer" ) ]
+impl crate::UnboxedTimer for GlommioCt
+{
+	type Sleep = GlommioSleep;
+
+	fn sleep_unboxed( &self, dur: Duration ) -> GlommioSleep
+	{
+		GlommioSleep::new( Instant::now() + dur, self.running.clone() )
+	}
+
+
+	fn sleep_until_unboxed( &self, deadline: Instant ) -> GlommioSleep
+	{
+		GlommioSleep::new( deadline, self.running.clone() )
+	}
+}
+
+
+
+/// The sleep future of [`GlommioCt`], returned by [`UnboxedTimer::sleep_unboxed`](crate::UnboxedTimer::sleep_unboxed).
+///
+/// The deadline is fixed when the sleep is created. The `glommio::timer::Timer` is created on first poll
+/// unless the deadline has passed already.
+///
+/// # Panics
+///
+/// - when polled outside of [`block_on`](GlommioCt::block_on) before the deadline has passed.
+/// - when polled or dropped on another thread than the one it was created on. The timer of glommio
+///   is not Send.
 //
-impl crate::Timer for GlommioCt
+pub struct GlommioSleep
+{
+	deadline: Instant                    ,
+	state   : SendWrapper< SleepState >  ,
+}
+
+
+struct SleepState
+{
+	running: Rc< Cell<bool> > ,
+	timer  : Option< Timer >  ,
+}
+
+
+impl GlommioSleep
+{
+	fn new( deadline: Instant, running: Rc< Cell<bool> > ) -> Self
+	{
+		Self{ deadline, state: SendWrapper::new( SleepState{ running, timer: None } ) }
+	}
+}
+
+
+// Doesn't show the state, as SendWrapper panics when formatted on another thread.
+//
+impl fmt::Debug for GlommioSleep
 {
-	fn sleep( &self, dur: std::time::Duration ) -> futures_core::future::BoxFuture<'static, ()>
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
 	{
-		futures_timer::Delay::new( dur ).boxed()
+		f.debug_struct( "GlommioSleep" )
+
+			.field( "deadline", &self.deadline )
+			.finish_non_exhaustive()
 	}
 }
 
 
+impl Future for GlommioSleep
+{
+	type Output = ();
+
+	fn poll( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<()>
+	{
+		let now = Instant::now();
+
+		if now >= self.deadline
+		{
+			return Poll::Ready(());
+		}
+
+		let deadline = self.deadline;
+		let state    = &mut *self.state;
+
+		// Glommio panics when creating a timer without a running executor.
+		//
+		assert!( state.running.get(), "The sleep of GlommioCt must be polled within GlommioCt::block_on." );
+
+		let timer = state.timer.get_or_insert_with( || Timer::new( deadline - now ) );
+
+		Pin::new( timer ).poll( cx ).map( drop )
+	}
+}
+
+
+
 impl YieldNow for GlommioCt
 {
 	/// Await this future in order to yield to the executor.
@@ -1,10 +1,10 @@
 use
 {
-	crate            :: { JoinHandle, SpawnHandle, LocalSpawnHandle, YieldNow      } ,
-	futures_task     :: { SpawnError, FutureObj, LocalFutureObj                    } ,
-	futures_util     :: { future::{ FutureExt }, task::{ SpawnExt, LocalSpawnExt } } ,
-	futures_executor :: { LocalSpawner                                             } ,
-
+	crate            :: { AbortHandle, JoinHandle, SpawnHandle, LocalSpawnHandle, YieldNow, BlockOn } ,
+	futures_task     :: { SpawnError, FutureObj, LocalFutureObj, Spawn, LocalSpawn                  } ,
+	futures_util     :: { future::{ FutureExt }, task::{ SpawnExt, LocalSpawnExt }                  } ,
+	futures_executor :: { LocalPool, LocalSpawner                                                   } ,
+	std              :: { cell::RefCell, future::Future, rc::Rc                                     } ,
 };
 
 
@@ -12,11 +12,12 @@ impl<Out: 'static + Send> SpawnHandle<Out> for LocalSpawner
 {
 	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
 	{
-		let (fut, handle) = future.remote_handle();
+		let (task, a_handle) = AbortHandle::wrap( future );
+		let (fut , handle  ) = task.remote_handle();
 
 		self.spawn( fut )?;
 
-		Ok( JoinHandle::remote_handle(handle))
+		Ok( JoinHandle::remote_handle(handle, a_handle) )
 	}
 }
 
@@ -26,11 +27,12 @@ impl<Out: 'static> LocalSpawnHandle<Out> for LocalSpawner
 {
 	fn spawn_handle_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
 	{
-		let (fut, handle) = future.remote_handle();
+		let (task, a_handle) = AbortHandle::wrap( future );
+		let (fut , handle  ) = task.remote_handle();
 
 		self.spawn_local( fut )?;
 
-		Ok( JoinHandle::remote_handle(handle) )
+		Ok( JoinHandle::remote_handle(handle, a_handle) )
 	}
 }
 
@@ -49,3 +51,147 @@ impl crate::Timer for LocalSpawner
 
 
 impl YieldNow for LocalSpawner {}
+
+
+
+
+/// An executor that owns a [`LocalPool`]. As opposed to [`LocalSpawner`], this implements [`BlockOn`] so it
+/// can be used like the other executors in this crate. It can be cloned and all clones share the same pool.
+///
+/// Spawning does not run the tasks, they only make progress while [`block_on`](BlockOn::block_on) is running.
+///
+/// # Panics
+///
+/// Calling [`block_on`](BlockOn::block_on) from within a task running on the same pool, or from within
+/// any other executor from the futures library, will panic.
+//
+#[ derive( Debug, Clone ) ]
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "localpool" )) ) ]
+//
+pub struct LocalPoolExec
+{
+	pool   : Rc< RefCell<LocalPool> > ,
+	spawner: LocalSpawner             ,
+}
+
+
+
+impl LocalPoolExec
+{
+	/// Create a new executor with it's own [`LocalPool`].
+	//
+	pub fn new() -> Self
+	{
+		Self::from( LocalPool::new() )
+	}
+
+
+	/// Obtain a [`LocalSpawner`] for the pool.
+	//
+	pub fn spawner( &self ) -> LocalSpawner
+	{
+		self.spawner.clone()
+	}
+
+
+	/// Run all tasks in the pool to completion.
+	///
+	/// # Panics
+	///
+	/// See the documentation of [`LocalPoolExec`].
+	//
+	pub fn run( &self )
+	{
+		self.pool.borrow_mut().run();
+	}
+}
+
+
+
+impl Default for LocalPoolExec
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+
+
+impl From<LocalPool> for LocalPoolExec
+{
+	fn from( pool: LocalPool ) -> Self
+	{
+		let spawner = pool.spawner();
+
+		Self{ pool: Rc::new( RefCell::new( pool ) ), spawner }
+	}
+}
+
+
+
+impl BlockOn for LocalPoolExec
+{
+	fn block_on<F: Future>( &self, future: F ) -> F::Output
+	{
+		self.pool.borrow_mut().run_until( future )
+	}
+}
+
+
+
+impl Spawn for LocalPoolExec
+{
+	fn spawn_obj( &self, future: FutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		self.spawner.spawn_obj( future )
+	}
+}
+
+
+
+impl LocalSpawn for LocalPoolExec
+{
+	fn spawn_local_obj( &self, future: LocalFutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		self.spawner.spawn_local_obj( future )
+	}
+}
+
+
+
+impl<Out: 'static + Send> SpawnHandle<Out> for LocalPoolExec
+{
+	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.spawner.spawn_handle_obj( future )
+	}
+}
+
+
+
+impl<Out: 'static> LocalSpawnHandle<Out> for LocalPoolExec
+{
+	fn spawn_handle_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.spawner.spawn_handle_local_obj( future )
+	}
+}
+
+
+
+#[ cfg( feature = "timer" ) ]
+//
+#[ cfg_attr( nightly, doc(cfg(all( feature = "timer", feature = "localpool" ))) ) ]
+//
+impl crate::Timer for LocalPoolExec
+{
+	fn sleep( &self, dur: std::time::Duration ) -> futures_core::future::BoxFuture<'static, ()>
+	{
+		self.spawner.sleep( dur )
+	}
+}
+
+
+impl YieldNow for LocalPoolExec {}
@@ -0,0 +1,239 @@
+use
+{
+	crate::iface :: { Timer, now                                                          } ,
+	futures_core :: { future::BoxFuture                                                   } ,
+	futures_util :: { future::FutureExt                                                   } ,
+	std          :: { collections::BTreeMap, fmt, future::Future, pin::Pin                 } ,
+	std          :: { time::{ Duration, Instant }                                          } ,
+	std          :: { sync::{ Arc, Mutex, MutexGuard, PoisonError }                        } ,
+	std          :: { task::{ Context, Poll, Waker }                                       } ,
+};
+
+
+/// A clock that only moves when you tell it to. It implements [`Timer`], and the sleeps it creates only
+/// complete when the clock is moved past their deadline with [`advance`](ManualClock::advance) or
+/// [`advance_to_next_timer`](ManualClock::advance_to_next_timer).
+///
+/// This lets tests that use [`TimerExt::timeout`] or sleep run without waiting in real time. To use it with a
+/// real executor for spawning, wrap the executor in a [`MockTimer`].
+///
+/// The deadline of a sleep is fixed when [`Timer::sleep`] is called, not when it is first polled, so it
+/// counts as pending right away. Advancing the clock wakes the tasks waiting on sleeps that are due, but it
+/// does not run them. They run the next time the executor polls them, so await something that lets them
+/// run, like their `JoinHandle`, before checking the results.
+///
+/// For [`Timer::current_time`], the clock starts at the [`Instant`] it was created at and moves forward
+/// with it's own time, so an [`Interval`](crate::Interval) sees the ticks it missed.
+///
+/// It can be cloned and all clones share the same time and timers.
+///
+/// ```
+/// use async_executors::{ ManualClock, Timer };
+/// use futures::FutureExt;
+/// use std::time::Duration;
+///
+/// let clock     = ManualClock::new();
+/// let mut sleep = clock.sleep( Duration::from_secs( 60 ) );
+///
+/// assert!( (&mut sleep).now_or_never().is_none() );
+///
+/// assert_eq!( clock.advance_to_next_timer(), Some( Duration::from_secs( 60 ) ) );
+///
+/// assert!( sleep.now_or_never().is_some() );
+/// ```
+//
+#[ derive( Clone ) ]
+//
+pub struct ManualClock
+{
+	shared: Arc< Mutex<State> > ,
+	start : Option<Instant>     ,
+}
+
+
+
+#[ derive( Debug, Default ) ]
+//
+struct State
+{
+	now       : Duration                                   ,
+	timers    : BTreeMap< (Duration, u64), Option<Waker> > ,
+	next_timer: u64                                        ,
+}
+
+
+
+impl ManualClock
+{
+	/// Create a clock at time zero.
+	//
+	pub fn new() -> Self
+	{
+		Self{ shared: Arc::default(), start: now() }
+	}
+
+
+	/// The time the clock has been advanced by since it was created.
+	//
+	pub fn now( &self ) -> Duration
+	{
+		self.lock().now
+	}
+
+
+	/// The number of sleeps that have not reached their deadline yet and have not been dropped.
+	//
+	pub fn pending_timers( &self ) -> usize
+	{
+		self.lock().timers.len()
+	}
+
+
+	/// Move the clock forward by `dur` and wake the sleeps that are due.
+	//
+	pub fn advance( &self, dur: Duration )
+	{
+		let state = self.lock();
+		let now   = state.now.saturating_add( dur );
+
+		Self::fire( state, now );
+	}
+
+
+	/// Move the clock to the earliest pending deadline and wake the sleeps that are due. Returns the new
+	/// time, or `None` if no sleep is pending, in which case the clock does not move.
+	//
+	pub fn advance_to_next_timer( &self ) -> Option<Duration>
+	{
+		let state = self.lock();
+
+		let &(deadline, _) = state.timers.keys().next()?;
+
+		Self::fire( state, deadline );
+
+		Some( deadline )
+	}
+
+
+	// Set the time and wake all timers that are due. The wakers are called after releasing the lock,
+	// since waking might poll the task right away on some executors.
+	//
+	fn fire( mut state: MutexGuard<'_, State>, now: Duration )
+	{
+		let later = state.timers.split_off( &(now, u64::MAX) );
+		let due   = std::mem::replace( &mut state.timers, later );
+
+		state.now = now;
+		drop( state );
+
+		due.into_values().flatten().for_each( Waker::wake );
+	}
+
+
+	// No user code runs while holding the lock, so poisoning can be ignored.
+	//
+	fn lock( &self ) -> MutexGuard<'_, State>
+	{
+		self.shared.lock().unwrap_or_else( PoisonError::into_inner )
+	}
+}
+
+
+
+impl Default for ManualClock
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+
+
+impl fmt::Debug for ManualClock
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		let state = self.lock();
+
+		f.debug_struct( "ManualClock" )
+
+			.field( "now"   , &state.now          )
+			.field( "timers", &state.timers.len() )
+			.finish()
+	}
+}
+
+
+
+impl Timer for ManualClock
+{
+	fn sleep( &self, dur: Duration ) -> BoxFuture<'static, ()>
+	{
+		let mut state = self.lock();
+		let deadline  = state.now.saturating_add( dur );
+
+		state.next_timer += 1;
+
+		let key = ( deadline, state.next_timer );
+
+		// A sleep that is due right away is never registered, so it's first poll completes it.
+		//
+		if deadline > state.now
+		{
+			state.timers.insert( key, None );
+		}
+
+		Sleep{ clock: self.clone(), key }.boxed()
+	}
+
+
+	fn current_time( &self ) -> Option<Instant>
+	{
+		self.start.map( |start| start + self.now() )
+	}
+}
+
+
+
+// Resolves once the clock reaches the deadline. The timer is removed from the clock when it fires or
+// when the sleep is dropped.
+//
+#[ derive( Debug ) ]
+//
+struct Sleep
+{
+	clock: ManualClock     ,
+	key  : (Duration, u64) ,
+}
+
+
+impl Future for Sleep
+{
+	type Output = ();
+
+	fn poll( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<()>
+	{
+		let mut state = self.clock.lock();
+
+		match state.timers.get_mut( &self.key )
+		{
+			Some( waker ) =>
+			{
+				*waker = Some( cx.waker().clone() );
+				Poll::Pending
+			}
+
+			None => Poll::Ready(()),
+		}
+	}
+}
+
+
+impl Drop for Sleep
+{
+	fn drop( &mut self )
+	{
+		self.clock.lock().timers.remove( &self.key );
+	}
+}
@@ -0,0 +1,522 @@
+use
+{
+	crate::iface :: { *                                                                   } ,
+	futures_task :: { SpawnError, FutureObj, LocalFutureObj, Spawn, LocalSpawn           } ,
+	futures_util :: { future::FutureExt, task::AtomicWaker                                } ,
+	pin_project  :: { pin_project                                                         } ,
+	std          :: { future::Future, pin::Pin, task::{ Context, Poll, Waker, Wake }      } ,
+	std          :: { panic::{ catch_unwind, resume_unwind, AssertUnwindSafe }            } ,
+	std          :: { sync::{ Arc, atomic::{ AtomicU64, Ordering } }                      } ,
+	std          :: { time::{ Duration, Instant }                                         } ,
+};
+
+
+/// An executor wrapper that collects metrics about the tasks spawned through it. Call
+/// [`snapshot`](Metered::snapshot) to obtain the numbers.
+///
+/// It counts the tasks spawned, completed, cancelled and panicked, and records in a [`Histogram`]
+/// how long each poll of a task takes and how long a task waits to be polled after being spawned
+/// or woken up.
+///
+/// A task that the executor refuses to spawn is counted as spawned and cancelled. Clones of a
+/// `Metered` share the metrics. Closures passed to [`SpawnBlocking`] are not counted.
+///
+/// On Wasm there is no clock in std, so the histograms remain empty.
+///
+/// ```
+/// use async_executors::{ Metered, SpawnHandle, SpawnHandleExt };
+///
+/// async fn run( exec: impl SpawnHandle<()> )
+/// {
+///    let exec = Metered::new( exec );
+///
+///    exec.spawn_handle( async {} ).expect( "spawn" ).await;
+///
+///    let metrics = exec.snapshot();
+///
+///    assert_eq!( metrics.spawned  , 1 );
+///    assert_eq!( metrics.completed, 1 );
+///    assert_eq!( metrics.active() , 0 );
+///
+///    assert!( metrics.poll_duration.count() >= 1 );
+///    assert!( metrics.poll_duration.max() <= metrics.poll_duration.sum() );
+/// }
+/// ```
+//
+#[ derive( Debug, Clone ) ]
+//
+pub struct Metered<E>
+{
+	exec   : E              ,
+	metrics: Arc< Metrics > ,
+}
+
+
+
+impl<E> Metered<E>
+{
+	/// Wrap an executor.
+	//
+	pub fn new( exec: E ) -> Self
+	{
+		Self{ exec, metrics: Arc::default() }
+	}
+
+
+	/// The metrics collected so far.
+	//
+	pub fn snapshot( &self ) -> TaskMetrics
+	{
+		let m = &self.metrics;
+
+		// The counters are independent, so tasks can end while they are loaded. That's why
+		// TaskMetrics::active saturates.
+		//
+		TaskMetrics
+		{
+			spawned       : m.spawned  .load( Ordering::Relaxed ) ,
+			completed     : m.completed.load( Ordering::Relaxed ) ,
+			cancelled     : m.cancelled.load( Ordering::Relaxed ) ,
+			panicked      : m.panicked .load( Ordering::Relaxed ) ,
+			poll_duration : m.poll_duration .snapshot()           ,
+			schedule_delay: m.schedule_delay.snapshot()           ,
+		}
+	}
+
+
+	/// Access the wrapped executor.
+	//
+	pub fn inner( &self ) -> &E
+	{
+		&self.exec
+	}
+
+
+	fn meter<F: Future>( &self, future: F ) -> MeteredFut<F>
+	{
+		self.metrics.spawned.fetch_add( 1, Ordering::Relaxed );
+
+		MeteredFut
+		{
+			future,
+			waker  : Arc::new( DelayWaker::new() )                         ,
+			outcome: Outcome{ metrics: self.metrics.clone(), done: false } ,
+		}
+	}
+}
+
+
+
+/// The metrics of the tasks spawned on a [`Metered`] executor.
+//
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+//
+pub struct TaskMetrics
+{
+	/// The number of tasks spawned.
+	//
+	pub spawned: u64,
+
+	/// The number of tasks that ran to completion.
+	//
+	pub completed: u64,
+
+	/// The number of tasks that were dropped before completing, e.g. because they were aborted.
+	//
+	pub cancelled: u64,
+
+	/// The number of tasks that panicked.
+	//
+	pub panicked: u64,
+
+	/// How long each poll of a task took.
+	//
+	pub poll_duration: Histogram,
+
+	/// How long tasks waited to be polled after being spawned or woken up.
+	//
+	pub schedule_delay: Histogram,
+}
+
+
+impl TaskMetrics
+{
+	/// The number of tasks that have been spawned and have not ended yet.
+	//
+	pub fn active( &self ) -> u64
+	{
+		self.spawned.saturating_sub( self.completed + self.cancelled + self.panicked )
+	}
+}
+
+
+
+const BUCKETS: usize = 32;
+
+
+/// A histogram of durations with buckets whose bounds are powers of two in microseconds. The first bucket
+/// holds durations under 1µs, the second under 2µs, the third under 4µs and so on. The last bucket holds
+/// everything that doesn't fit in the others.
+//
+#[ derive( Debug, Clone, PartialEq, Eq, Default ) ]
+//
+pub struct Histogram
+{
+	buckets: [u64; BUCKETS] ,
+	sum    : Duration       ,
+	max    : Duration       ,
+}
+
+
+impl Histogram
+{
+	/// The number of recorded durations.
+	//
+	pub fn count( &self ) -> u64
+	{
+		self.buckets.iter().sum()
+	}
+
+
+	/// The sum of all recorded durations.
+	//
+	pub fn sum( &self ) -> Duration
+	{
+		self.sum
+	}
+
+
+	/// The longest recorded duration.
+	//
+	pub fn max( &self ) -> Duration
+	{
+		self.max
+	}
+
+
+	/// The average of the recorded durations, or `None` if nothing was recorded.
+	//
+	pub fn mean( &self ) -> Option<Duration>
+	{
+		let count = u32::try_from( self.count() ).unwrap_or( u32::MAX );
+
+		( count > 0 ).then( || self.sum / count )
+	}
+
+
+	/// An upper bound for the given quantile, for example `0.99` for the 99th percentile. This is the upper bound
+	/// of the bucket in which the quantile falls, capped by the longest duration recorded. Returns `None` if nothing
+	/// was recorded.
+	//
+	pub fn quantile( &self, q: f64 ) -> Option<Duration>
+	{
+		let count = self.count();
+
+		if count == 0
+		{
+			return None;
+		}
+
+		// This is a rank, so the precision loss of the conversions doesn't matter.
+		//
+		#[ allow( clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss ) ]
+		//
+		let rank = ( ( q.clamp( 0.0, 1.0 ) * count as f64 ).ceil() as u64 ).max( 1 );
+
+		let mut seen = 0;
+
+		self.buckets().find_map( |(bound, n)|
+		{
+			seen += n;
+			( seen >= rank ).then( || bound.min( self.max ) )
+		})
+	}
+
+
+	/// The buckets of the histogram as the exclusive upper bound of the bucket and the number of durations
+	/// recorded in it. The upper bound of the last bucket is [`Duration::MAX`].
+	//
+	pub fn buckets( &self ) -> impl Iterator< Item = (Duration, u64) > + '_
+	{
+		self.buckets.iter().enumerate().map( |(i, n)|
+		{
+			let bound = if i == BUCKETS - 1 { Duration::MAX } else { Duration::from_micros( 1 << i ) };
+
+			(bound, *n)
+		})
+	}
+}
+
+
+
+// The shared counters.
+//
+#[ derive( Debug, Default ) ]
+//
+struct Metrics
+{
+	spawned       : AtomicU64       ,
+	completed     : AtomicU64       ,
+	cancelled     : AtomicU64       ,
+	panicked      : AtomicU64       ,
+	poll_duration : AtomicHistogram ,
+	schedule_delay: AtomicHistogram ,
+}
+
+
+
+#[ derive( Debug, Default ) ]
+//
+struct AtomicHistogram
+{
+	buckets: [AtomicU64; BUCKETS] ,
+	sum    : AtomicU64            ,
+	max    : AtomicU64            ,
+}
+
+
+impl AtomicHistogram
+{
+	fn record( &self, dur: Duration )
+	{
+		let micros = u64::try_from( dur.as_micros() ).unwrap_or( u64::MAX );
+		let nanos  = u64::try_from( dur.as_nanos () ).unwrap_or( u64::MAX );
+		let bucket = ( 64 - micros.leading_zeros() as usize ).min( BUCKETS - 1 );
+
+		self.buckets[bucket].fetch_add( 1    , Ordering::Relaxed );
+		self.sum            .fetch_add( nanos, Ordering::Relaxed );
+		self.max            .fetch_max( nanos, Ordering::Relaxed );
+	}
+
+
+	fn snapshot( &self ) -> Histogram
+	{
+		Histogram
+		{
+			buckets: std::array::from_fn( |i| self.buckets[i].load( Ordering::Relaxed ) ) ,
+			sum    : Duration::from_nanos( self.sum.load( Ordering::Relaxed ) )         ,
+			max    : Duration::from_nanos( self.max.load( Ordering::Relaxed ) )         ,
+		}
+	}
+}
+
+
+
+// Wraps the waker of the task to record when it was woken up.
+//
+#[ derive( Debug ) ]
+//
+struct DelayWaker
+{
+	inner: AtomicWaker     ,
+
+	// When the task was spawned. None on Wasm.
+	//
+	base : Option<Instant> ,
+
+	// The time of the first wake up since the last poll, as nanoseconds since base plus one. Zero when the task
+	// hasn't been woken up since.
+	//
+	woken: AtomicU64       ,
+}
+
+
+impl DelayWaker
+{
+	// A task counts as woken up when it's spawned.
+	//
+	fn new() -> Self
+	{
+		let base = now();
+
+		Self{ inner: AtomicWaker::new(), woken: AtomicU64::new( u64::from( base.is_some() ) ), base }
+	}
+
+
+	// Record how long ago the task was scheduled and update the waker to forward to.
+	//
+	fn polled( &self, at: Option<Instant>, waker: &Waker, metrics: &Metrics )
+	{
+		let woken = self.woken.swap( 0, Ordering::Relaxed );
+
+		if let ( Some(at), Some(base), Some(nanos) ) = ( at, self.base, woken.checked_sub( 1 ) )
+		{
+			metrics.schedule_delay.record( at.saturating_duration_since( base + Duration::from_nanos( nanos ) ) );
+		}
+
+		self.inner.register( waker );
+	}
+}
+
+
+impl Wake for DelayWaker
+{
+	fn wake( self: Arc<Self> )
+	{
+		self.wake_by_ref();
+	}
+
+
+	fn wake_by_ref( self: &Arc<Self> )
+	{
+		// Keep the time of the first wake up since the last poll.
+		//
+		if let Some( base ) = self.base
+		{
+			let nanos = u64::try_from( base.elapsed().as_nanos() ).unwrap_or( u64::MAX ).saturating_add( 1 );
+
+			let _ = self.woken.compare_exchange( 0, nanos, Ordering::Relaxed, Ordering::Relaxed );
+		}
+
+		self.inner.wake();
+	}
+}
+
+
+
+// Counts the task as cancelled if it's dropped before completing or panicking.
+//
+#[ derive( Debug ) ]
+//
+struct Outcome
+{
+	metrics: Arc< Metrics > ,
+	done   : bool           ,
+}
+
+
+impl Drop for Outcome
+{
+	fn drop( &mut self )
+	{
+		if !self.done
+		{
+			self.metrics.cancelled.fetch_add( 1, Ordering::Relaxed );
+		}
+	}
+}
+
+
+
+// The outcome comes first, so a task is counted as cancelled before the future it wraps is dropped.
+//
+#[ pin_project ]
+//
+#[ derive( Debug ) ]
+//
+struct MeteredFut<F>
+{
+	       outcome: Outcome           ,
+	       waker  : Arc< DelayWaker > ,
+	#[pin] future : F                 ,
+}
+
+
+impl<F: Future> Future for MeteredFut<F>
+{
+	type Output = F::Output;
+
+	fn poll( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Self::Output>
+	{
+		let this    = self.project();
+		let metrics = &this.outcome.metrics;
+		let start   = now();
+
+		this.waker.polled( start, cx.waker(), metrics );
+
+		let waker  = Waker::from( this.waker.clone() );
+		let mut cx = Context::from_waker( &waker );
+		let future = this.future;
+		let result = catch_unwind( AssertUnwindSafe( || future.poll( &mut cx ) ) );
+
+		if let Some( start ) = start
+		{
+			metrics.poll_duration.record( start.elapsed() );
+		}
+
+		match result
+		{
+			Ok( Poll::Pending ) => Poll::Pending,
+
+			Ok( Poll::Ready(out) ) =>
+			{
+				this.outcome.done = true;
+				metrics.completed.fetch_add( 1, Ordering::Relaxed );
+
+				Poll::Ready( out )
+			}
+
+			Err( payload ) =>
+			{
+				this.outcome.done = true;
+				metrics.panicked.fetch_add( 1, Ordering::Relaxed );
+
+				resume_unwind( payload )
+			}
+		}
+	}
+}
+
+
+
+/// A task the executor refuses to spawn is counted as spawned and cancelled.
+//
+impl<E> Spawn for Metered<E> where E: Spawn
+{
+	fn spawn_obj( &self, future: FutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		self.exec.spawn_obj( FutureObj::new( self.meter( future ).boxed() ) )
+	}
+}
+
+
+
+/// A task the executor refuses to spawn is counted as spawned and cancelled.
+//
+impl<E> LocalSpawn for Metered<E> where E: LocalSpawn
+{
+	fn spawn_local_obj( &self, future: LocalFutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		self.exec.spawn_local_obj( LocalFutureObj::new( self.meter( future ).boxed_local() ) )
+	}
+}
+
+
+
+/// A task the executor refuses to spawn is counted as spawned and cancelled.
+//
+impl<E, Out> SpawnHandle<Out> for Metered<E> where E: SpawnHandle<Out>, Out: 'static + Send
+{
+	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.exec.spawn_handle_obj( FutureObj::new( self.meter( future ).boxed() ) )
+	}
+
+
+	fn spawn_handle_obj_named( &self, future: FutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.exec.spawn_handle_obj_named( FutureObj::new( self.meter( future ).boxed() ), name )
+	}
+}
+
+
+
+/// A task the executor refuses to spawn is counted as spawned and cancelled.
+//
+impl<E, Out> LocalSpawnHandle<Out> for Metered<E> where E: LocalSpawnHandle<Out>, Out: 'static
+{
+	fn spawn_handle_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.exec.spawn_handle_local_obj( LocalFutureObj::new( self.meter( future ).boxed_local() ) )
+	}
+
+
+	fn spawn_handle_local_obj_named( &self, future: LocalFutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.exec.spawn_handle_local_obj_named( LocalFutureObj::new( self.meter( future ).boxed_local() ), name )
+	}
+}
+
+
+
+forward_traits!( Metered );
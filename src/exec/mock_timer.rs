@@ -0,0 +1,151 @@
+use
+{
+	crate::iface :: { *                                                        } ,
+	super        :: { ManualClock                                              } ,
+	futures_task :: { SpawnError, FutureObj, LocalFutureObj, Spawn, LocalSpawn } ,
+	futures_core :: { future::BoxFuture                                        } ,
+	std          :: { time::Duration                                           } ,
+};
+
+
+/// An executor wrapper that implements [`Timer`] with a [`ManualClock`] and forwards everything else to the
+/// wrapped executor. Use it to test code that takes an executor with a timer, like
+/// `impl SpawnHandle<T> + Timer`, without sleeping in real time.
+///
+/// The wrapped executor does not need to implement `Timer`.
+///
+/// ```
+/// use async_executors::{ MockTimer, ManualClock, SpawnHandle, SpawnHandleExt, TimerExt };
+/// use std::time::Duration;
+///
+/// async fn run( exec: impl SpawnHandle<bool> )
+/// {
+///    let clock   = ManualClock::new();
+///    let exec    = MockTimer::new( exec, clock.clone() );
+///    let timeout = exec.timeout( Duration::from_secs( 60 ), futures::future::pending::<()>() );
+///
+///    let handle = exec.spawn_handle( async move { timeout.await.is_err() } ).expect( "spawn" );
+///
+///    clock.advance( Duration::from_secs( 60 ) );
+///
+///    assert!( handle.await );
+/// }
+/// ```
+//
+#[ derive( Debug, Clone ) ]
+//
+pub struct MockTimer<E>
+{
+	exec : E           ,
+	clock: ManualClock ,
+}
+
+
+
+impl<E> MockTimer<E>
+{
+	/// Wrap an executor, using `clock` for it's timer.
+	//
+	pub fn new( exec: E, clock: ManualClock ) -> Self
+	{
+		Self{ exec, clock }
+	}
+
+
+	/// The clock used for the timer.
+	//
+	pub fn clock( &self ) -> &ManualClock
+	{
+		&self.clock
+	}
+
+
+	/// Access the wrapped executor.
+	//
+	pub fn inner( &self ) -> &E
+	{
+		&self.exec
+	}
+
+
+	/// Unwrap the executor.
+	//
+	pub fn into_inner( self ) -> E
+	{
+		self.exec
+	}
+}
+
+
+
+impl<E> Spawn for MockTimer<E> where E: Spawn
+{
+	fn spawn_obj( &self, future: FutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		self.exec.spawn_obj( future )
+	}
+}
+
+
+
+impl<E> LocalSpawn for MockTimer<E> where E: LocalSpawn
+{
+	fn spawn_local_obj( &self, future: LocalFutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		self.exec.spawn_local_obj( future )
+	}
+}
+
+
+
+impl<E, Out> SpawnHandle<Out> for MockTimer<E> where E: SpawnHandle<Out>, Out: 'static + Send
+{
+	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.exec.spawn_handle_obj( future )
+	}
+
+
+	fn spawn_handle_obj_named( &self, future: FutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.exec.spawn_handle_obj_named( future, name )
+	}
+}
+
+
+
+impl<E, Out> LocalSpawnHandle<Out> for MockTimer<E> where E: LocalSpawnHandle<Out>, Out: 'static
+{
+	fn spawn_handle_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.exec.spawn_handle_local_obj( future )
+	}
+
+
+	fn spawn_handle_local_obj_named( &self, future: LocalFutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.exec.spawn_handle_local_obj_named( future, name )
+	}
+}
+
+
+
+/// Sleeps complete when the clock is advanced, see [`ManualClock`].
+//
+impl<E> Timer for MockTimer<E>
+{
+	fn sleep( &self, dur: Duration ) -> BoxFuture<'static, ()>
+	{
+		self.clock.sleep( dur )
+	}
+
+
+	fn current_time( &self ) -> Option<std::time::Instant>
+	{
+		self.clock.current_time()
+	}
+}
+
+
+
+forward_traits!( MockTimer: BlockOn, Shutdown, YieldNow, TokioIo, SpawnBlocking );
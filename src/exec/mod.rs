@@ -1,15 +1,29 @@
+// Declared first, so the wrappers below can use it's macro.
+//
+#[ macro_use ] mod forward;
+
 #[ cfg( feature = "tokio_ct"     ) ] mod tokio_ct;
 #[ cfg( feature = "tokio_ct"     ) ] pub use tokio_ct::*;
 
 #[ cfg( feature = "tokio_tp"     ) ] mod tokio_tp;
 #[ cfg( feature = "tokio_tp"     ) ] pub use tokio_tp::*;
 
+#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct" )) ] mod tokio_spawn;
+
 #[ cfg( feature = "async_global" ) ] mod async_global;
 #[ cfg( feature = "async_global" ) ] pub use async_global::*;
 
 #[ cfg( feature = "async_std"    ) ] mod async_std;
 #[ cfg( feature = "async_std"    ) ] pub use async_std::*;
 
+#[ cfg(all( any( feature = "async_std", feature = "async_global" ), not( target_os = "unknown" ) )) ]
+//
+mod async_io_sleep;
+
+#[ cfg(all( any( feature = "async_std", feature = "async_global" ), not( target_os = "unknown" ) )) ]
+//
+pub use async_io_sleep::*;
+
 #[ cfg( feature = "glommio"      ) ] mod glommio_ct;
 #[ cfg( feature = "glommio"      ) ] pub use glommio_ct::*;
 
@@ -17,9 +31,52 @@
 #[ cfg( feature = "bindgen"      ) ] pub use bindgen::*;
 
 #[ cfg( feature = "localpool"    ) ] mod localpool;
+#[ cfg( feature = "localpool"    ) ] pub use localpool::*;
+
+#[ cfg( feature = "sim"          ) ] mod sim;
+#[ cfg( feature = "sim"          ) ] pub use sim::*;
+
 #[ cfg( feature = "threadpool"   ) ] mod threadpool;
+
+#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct", feature = "async_std", feature = "async_global",
+            feature = "glommio", feature = "threadpool", feature = "localpool" )) ]
+//
+mod dyn_exec;
+
+#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct", feature = "async_std", feature = "async_global",
+            feature = "glommio", feature = "threadpool", feature = "localpool" )) ]
+//
+pub use dyn_exec::*;
+
 #[ cfg( feature = "tracing"      ) ] mod tracing;
 
+mod bounded;
+pub use bounded::*;
+
+mod cancel_scope;
+pub use cancel_scope::*;
+
+mod catch_unwind;
+pub use catch_unwind::*;
+
+mod manual_clock;
+pub use manual_clock::*;
+
+mod metered;
+pub use metered::*;
+
+mod mock_timer;
+pub use mock_timer::*;
+
+#[ cfg( feature = "timer" ) ] mod timer_only;
+#[ cfg( feature = "timer" ) ] pub use timer_only::*;
+
+mod tracked;
+pub use tracked::*;
+
+mod watchdog;
+pub use watchdog::*;
+
 
 
 
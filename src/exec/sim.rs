@@ -0,0 +1,417 @@
+use
+{
+	crate          :: { SpawnHandle, LocalSpawnHandle, JoinHandle, AbortHandle, BlockOn, BlockingHandle, YieldNow } ,
+	crate          :: { ManualClock, Timer                                                                      } ,
+	futures_task   :: { FutureObj, LocalFutureObj, Spawn, LocalSpawn, SpawnError                                 } ,
+	futures_util   :: { future::{ FutureExt, BoxFuture }                                                        } ,
+	std            :: { cell::{ Cell, RefCell }, collections::{ BTreeMap, BTreeSet }, fmt, future::Future      } ,
+	std            :: { pin::pin, rc::Rc, task::{ Context, Poll, Wake, Waker }, time::Duration                 } ,
+	std            :: { sync::{ Arc, Mutex, MutexGuard, PoisonError }                                          } ,
+};
+
+
+/// A single threaded executor for tests which runs ready tasks in an order drawn from a seeded RNG and
+/// implements [`Timer`](crate::Timer) with virtual time.
+///
+/// Fuzz your code against task orderings by running it with many seeds. Since everything runs on one
+/// thread and time is virtual, a failing ordering can be replayed by running with the same seed. This only
+/// holds if the code under test is deterministic itself, so it should not use real threads, real time or
+/// other executors.
+///
+/// Tasks only make progress while [`block_on`](BlockOn::block_on) or [`run`](SimExec::run) is running.
+/// Whenever no task is ready, virtual time jumps to the next timer, so sleeping takes no real time.
+/// [`SpawnBlocking`](crate::SpawnBlocking) runs the closure right away on the current thread.
+///
+/// It can be cloned and all clones share the same tasks, RNG and clock.
+///
+/// ```
+/// use async_executors::{ SimExec, BlockOn, SpawnHandleExt, Timer };
+/// use std::time::Duration;
+///
+/// for seed in 0..100
+/// {
+///    let exec = SimExec::new( seed );
+///
+///    let out = exec.block_on( async
+///    {
+///       let a = exec.spawn_handle( async { 1 } ).expect( "spawn" );
+///       let b = exec.spawn_handle( async { 2 } ).expect( "spawn" );
+///
+///       exec.sleep( Duration::from_secs( 3600 ) ).await;
+///
+///       a.await + b.await
+///    });
+///
+///    assert_eq!( out, 3 );
+///    assert_eq!( exec.now(), Duration::from_secs( 3600 ) );
+/// }
+/// ```
+///
+/// # Panics
+///
+/// A panic in a task spawned without a [`JoinHandle`] unwinds out of `block_on` or `run`.
+///
+/// [`block_on`](BlockOn::block_on) panics when the future can not make progress anymore, that is when no
+/// task is ready and no timer is pending. It also panics when called from within a task of the same executor.
+//
+#[ derive( Clone ) ]
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "sim" )) ) ]
+//
+pub struct SimExec
+{
+	inner: Rc<Inner>,
+}
+
+
+
+struct Inner
+{
+	seed     : u64                                                   ,
+	rng      : Cell<u64>                                             ,
+	tasks    : RefCell< BTreeMap<u64, LocalFutureObj<'static, ()>> > ,
+	next_task: Cell<u64>                                             ,
+	running  : Cell<bool>                                            ,
+	shared   : Arc< Shared >                                         ,
+	clock    : ManualClock                                           ,
+}
+
+
+
+// The set of ready tasks, which wakers need access to, so it must be Send.
+//
+#[ derive( Debug, Default ) ]
+//
+struct Shared
+{
+	ready: Mutex< BTreeSet<u64> >,
+}
+
+
+impl Shared
+{
+	// No user code runs while holding the lock, so poisoning can be ignored.
+	//
+	fn lock( &self ) -> MutexGuard<'_, BTreeSet<u64>>
+	{
+		self.ready.lock().unwrap_or_else( PoisonError::into_inner )
+	}
+}
+
+
+
+// The id used for the future passed to block_on. Task ids start at 1.
+//
+const MAIN: u64 = 0;
+
+
+
+impl SimExec
+{
+	/// Create an executor. The same seed gives the same order of execution.
+	//
+	pub fn new( seed: u64 ) -> Self
+	{
+		let inner = Inner
+		{
+			seed                             ,
+			rng      : Cell::new( seed )     ,
+			tasks    : RefCell::default()    ,
+			next_task: Cell::new( MAIN + 1 ) ,
+			running  : Cell::new( false )    ,
+			shared   : Arc::default()        ,
+			clock    : ManualClock::new()    ,
+		};
+
+		Self{ inner: Rc::new( inner ) }
+	}
+
+
+	/// The seed this executor was created with.
+	//
+	pub fn seed( &self ) -> u64
+	{
+		self.inner.seed
+	}
+
+
+	/// The virtual time elapsed since the executor was created.
+	//
+	pub fn now( &self ) -> Duration
+	{
+		self.inner.clock.now()
+	}
+
+
+	/// Run until no task is ready and no timer is pending. Tasks that wait for something else are left alone.
+	///
+	/// # Panics
+	///
+	/// See the documentation of [`SimExec`].
+	//
+	pub fn run( &self )
+	{
+		let _running = self.enter();
+
+		loop
+		{
+			match self.next_ready()
+			{
+				Some( id ) => self.poll_task( id ),
+				None       => if self.inner.clock.advance_to_next_timer().is_none() { return },
+			}
+		}
+	}
+
+
+	// Mark the executor as running until the guard is dropped, also when a task panics.
+	//
+	fn enter( &self ) -> Running<'_>
+	{
+		assert!( !self.inner.running.replace( true ), "SimExec: cannot block_on or run from within a task of the same executor." );
+
+		Running( &self.inner.running )
+	}
+
+
+	// SplitMix64, which is good enough to shuffle tasks and needs no dependency.
+	//
+	fn random( &self ) -> u64
+	{
+		let mut z = self.inner.rng.get().wrapping_add( 0x9E37_79B9_7F4A_7C15 );
+		self.inner.rng.set( z );
+
+		z = ( z ^ (z >> 30) ).wrapping_mul( 0xBF58_476D_1CE4_E5B9 );
+		z = ( z ^ (z >> 27) ).wrapping_mul( 0x94D0_49BB_1331_11EB );
+
+		z ^ (z >> 31)
+	}
+
+
+	// Take a random task from the ready set.
+	//
+	fn next_ready( &self ) -> Option<u64>
+	{
+		let mut ready = self.inner.shared.lock();
+		let     len   = ready.len() as u64;
+
+		if len == 0
+		{
+			return None;
+		}
+
+		let index = usize::try_from( self.random() % len ).expect( "index fits in usize" );
+		let id    = *ready.iter().nth( index ).expect( "index in bounds" );
+
+		ready.remove( &id );
+
+		Some( id )
+	}
+
+
+	fn waker( &self, id: u64 ) -> Waker
+	{
+		Waker::from( Arc::new( TaskWaker{ id, shared: self.inner.shared.clone() } ) )
+	}
+
+
+	// The task is taken out of the map while polling, so it can spawn new tasks.
+	//
+	fn poll_task( &self, id: u64 )
+	{
+		// The task might have completed already and still get woken up.
+		//
+		let Some( mut task ) = self.inner.tasks.borrow_mut().remove( &id ) else { return };
+
+		let waker = self.waker( id );
+
+		if task.poll_unpin( &mut Context::from_waker( &waker ) ).is_pending()
+		{
+			self.inner.tasks.borrow_mut().insert( id, task );
+		}
+	}
+}
+
+
+
+impl fmt::Debug for SimExec
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		f.debug_struct( "SimExec" )
+
+			.field( "seed" , &self.inner.seed                  )
+			.field( "tasks", &self.inner.tasks.borrow().len()  )
+			.field( "now"  , &self.now()                       )
+			.finish()
+	}
+}
+
+
+
+struct Running<'a>( &'a Cell<bool> );
+
+
+impl Drop for Running<'_>
+{
+	fn drop( &mut self )
+	{
+		self.0.set( false );
+	}
+}
+
+
+
+// Puts the task back in the ready set.
+//
+struct TaskWaker
+{
+	id    : u64           ,
+	shared: Arc< Shared > ,
+}
+
+
+impl Wake for TaskWaker
+{
+	fn wake( self: Arc<Self> )
+	{
+		self.wake_by_ref();
+	}
+
+
+	fn wake_by_ref( self: &Arc<Self> )
+	{
+		self.shared.lock().insert( self.id );
+	}
+}
+
+
+
+impl BlockOn for SimExec
+{
+	fn block_on<F: Future>( &self, future: F ) -> F::Output
+	{
+		let _running   = self.enter();
+		let mut future = pin!( future );
+		let waker      = self.waker( MAIN );
+
+		self.inner.shared.lock().insert( MAIN );
+
+		loop
+		{
+			match self.next_ready()
+			{
+				Some( MAIN ) =>
+				{
+					if let Poll::Ready( out ) = future.as_mut().poll( &mut Context::from_waker( &waker ) )
+					{
+						return out;
+					}
+				}
+
+				Some( id ) => self.poll_task( id ),
+
+				None => assert!
+				(
+					self.inner.clock.advance_to_next_timer().is_some(),
+					"SimExec: the future passed to block_on can not make progress, no task is ready and no timer is pending. Seed: {}",
+					self.inner.seed,
+				),
+			}
+		}
+	}
+}
+
+
+
+impl Spawn for SimExec
+{
+	fn spawn_obj( &self, future: FutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		self.spawn_local_obj( future.into() )
+	}
+}
+
+
+
+impl LocalSpawn for SimExec
+{
+	fn spawn_local_obj( &self, future: LocalFutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		let id = self.inner.next_task.get();
+
+		self.inner.next_task.set( id + 1 );
+		self.inner.tasks.borrow_mut().insert( id, future );
+		self.inner.shared.lock().insert( id );
+
+		Ok(())
+	}
+}
+
+
+
+impl<Out: 'static + Send> SpawnHandle<Out> for SimExec
+{
+	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.spawn_handle_local_obj( future.into() )
+	}
+}
+
+
+
+impl<Out: 'static> LocalSpawnHandle<Out> for SimExec
+{
+	fn spawn_handle_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let (task, a_handle) = AbortHandle::wrap( future );
+		let (fut , handle  ) = task.remote_handle();
+
+		self.spawn_local_obj( LocalFutureObj::new( fut.boxed_local() ) )?;
+
+		Ok( JoinHandle::remote_handle(handle, a_handle) )
+	}
+}
+
+
+
+impl YieldNow for SimExec {}
+
+
+
+/// Runs the closure right away on the current thread.
+//
+impl<R: Send + 'static> crate::SpawnBlocking<R> for SimExec
+{
+	fn spawn_blocking<F>( &self, f: F ) -> BlockingHandle<R>
+
+		where F   : FnOnce() -> R + Send + 'static ,
+	         Self: Sized                          ,
+	{
+		BlockingHandle::inline( f )
+	}
+
+
+	fn spawn_blocking_dyn( &self, f: Box< dyn FnOnce()->R + Send > ) -> BlockingHandle<R>
+	{
+		BlockingHandle::inline( f )
+	}
+}
+
+
+
+/// Sleeping uses virtual time, see [`SimExec`].
+//
+impl Timer for SimExec
+{
+	fn sleep( &self, dur: Duration ) -> BoxFuture<'static, ()>
+	{
+		self.inner.clock.sleep( dur )
+	}
+
+
+	fn current_time( &self ) -> Option<std::time::Instant>
+	{
+		self.inner.clock.current_time()
+	}
+}
@@ -1,6 +1,6 @@
 use
 {
-	crate            :: { JoinHandle, SpawnHandle               } ,
+	crate            :: { AbortHandle, JoinHandle, SpawnHandle  } ,
 	futures_task     :: { SpawnError, FutureObj                 } ,
 	futures_util     :: { future::{ FutureExt }, task::SpawnExt } ,
 	futures_executor :: { ThreadPool                            } ,
@@ -12,11 +12,12 @@ impl<Out: 'static + Send> SpawnHandle<Out> for ThreadPool
 {
 	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
 	{
-		let (fut, handle) = future.remote_handle();
+		let (task, a_handle) = AbortHandle::wrap( future );
+		let (fut , handle  ) = task.remote_handle();
 
 		self.spawn( fut )?;
 
-		Ok( JoinHandle::remote_handle(handle) )
+		Ok( JoinHandle::remote_handle(handle, a_handle) )
 	}
 }
 
@@ -25,6 +26,18 @@ impl crate::YieldNow for ThreadPool {}
 
 
 
+/// Blocks the current thread with [`futures_executor::block_on`]. Spawned tasks run on the threads of the pool.
+//
+impl crate::BlockOn for ThreadPool
+{
+	fn block_on<F: std::future::Future>( &self, future: F ) -> F::Output
+	{
+		futures_executor::block_on( future )
+	}
+}
+
+
+
 #[ cfg( feature = "timer" ) ]
 //
 #[ cfg_attr( nightly, doc(cfg(all( feature = "timer", feature = "async_global" ))) ) ]
@@ -0,0 +1,204 @@
+use
+{
+	crate::iface :: { *                                                        } ,
+	futures_task :: { SpawnError, FutureObj, LocalFutureObj, Spawn, LocalSpawn } ,
+	futures_core :: { future::BoxFuture                                        } ,
+	std          :: { time::Duration                                           } ,
+};
+
+
+/// A [`Timer`] backed by the [_futures-timer_](https://docs.rs/futures-timer) crate. It doesn't need an
+/// executor, futures-timer runs it's own thread on native targets and uses the browser timers on Wasm.
+///
+/// Use it where code needs a timer on it's own, or wrap an executor that has none in [`TimerOnly`].
+///
+/// ```
+/// use async_executors::{ FuturesTimer, TimerExt };
+/// use std::time::Duration;
+///
+/// let fut = FuturesTimer.timeout( Duration::from_millis( 10 ), futures::future::pending::<()>() );
+///
+/// assert!( futures::executor::block_on( fut ).is_err() );
+/// ```
+//
+#[ derive( Debug, Clone, Copy, Default, PartialEq, Eq ) ]
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "timer" )) ) ]
+//
+pub struct FuturesTimer;
+
+
+
+impl Timer for FuturesTimer
+{
+	fn sleep( &self, dur: Duration ) -> BoxFuture<'static, ()>
+	{
+		Box::pin( futures_timer::Delay::new( dur ) )
+	}
+}
+
+
+
+impl UnboxedTimer for FuturesTimer
+{
+	type Sleep = futures_timer::Delay;
+
+	fn sleep_unboxed( &self, dur: Duration ) -> Self::Sleep
+	{
+		futures_timer::Delay::new( dur )
+	}
+}
+
+
+
+/// An executor wrapper that implements [`Timer`] with a [`FuturesTimer`] and forwards everything else to the
+/// wrapped executor. Use it to pass an executor without a timer, like a third party [`Spawn`] implementation,
+/// to code that requires `Timer`.
+///
+/// When the wrapped executor has a timer of it's own, that one is hidden.
+///
+/// ```
+/// use async_executors::{ TimerOnly, Timer };
+/// use futures::{ executor::block_on, channel::oneshot, task::{ Spawn, SpawnExt, SpawnError, FutureObj } };
+/// use std::time::Duration;
+///
+/// // An executor from elsewhere, which runs every task on a thread of it's own.
+/// //
+/// struct Threads;
+///
+/// impl Spawn for Threads
+/// {
+///    fn spawn_obj( &self, future: FutureObj<'static, ()> ) -> Result<(), SpawnError>
+///    {
+///       std::thread::spawn( move || block_on( future ) );
+///       Ok(())
+///    }
+/// }
+///
+/// async fn run( exec: impl Spawn + Timer )
+/// {
+///    let (tx, rx) = oneshot::channel();
+///    let sleep    = exec.sleep( Duration::from_millis( 10 ) );
+///
+///    exec.spawn( async move { sleep.await; let _ = tx.send(()); } ).expect( "spawn" );
+///
+///    rx.await.expect( "sleep" );
+/// }
+///
+/// block_on( run( TimerOnly::new( Threads ) ) );
+/// ```
+//
+#[ derive( Debug, Clone ) ]
+//
+#[ cfg_attr( nightly, doc(cfg( feature = "timer" )) ) ]
+//
+pub struct TimerOnly<E>
+{
+	exec: E,
+}
+
+
+
+impl<E> TimerOnly<E>
+{
+	/// Wrap an executor.
+	//
+	pub fn new( exec: E ) -> Self
+	{
+		Self{ exec }
+	}
+
+
+	/// Access the wrapped executor.
+	//
+	pub fn inner( &self ) -> &E
+	{
+		&self.exec
+	}
+
+
+	/// Unwrap the executor.
+	//
+	pub fn into_inner( self ) -> E
+	{
+		self.exec
+	}
+}
+
+
+
+impl<E> Spawn for TimerOnly<E> where E: Spawn
+{
+	fn spawn_obj( &self, future: FutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		self.exec.spawn_obj( future )
+	}
+}
+
+
+
+impl<E> LocalSpawn for TimerOnly<E> where E: LocalSpawn
+{
+	fn spawn_local_obj( &self, future: LocalFutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		self.exec.spawn_local_obj( future )
+	}
+}
+
+
+
+impl<E, Out> SpawnHandle<Out> for TimerOnly<E> where E: SpawnHandle<Out>, Out: 'static + Send
+{
+	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.exec.spawn_handle_obj( future )
+	}
+
+
+	fn spawn_handle_obj_named( &self, future: FutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.exec.spawn_handle_obj_named( future, name )
+	}
+}
+
+
+
+impl<E, Out> LocalSpawnHandle<Out> for TimerOnly<E> where E: LocalSpawnHandle<Out>, Out: 'static
+{
+	fn spawn_handle_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.exec.spawn_handle_local_obj( future )
+	}
+
+
+	fn spawn_handle_local_obj_named( &self, future: LocalFutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.exec.spawn_handle_local_obj_named( future, name )
+	}
+}
+
+
+
+impl<E> Timer for TimerOnly<E>
+{
+	fn sleep( &self, dur: Duration ) -> BoxFuture<'static, ()>
+	{
+		FuturesTimer.sleep( dur )
+	}
+}
+
+
+
+impl<E> UnboxedTimer for TimerOnly<E>
+{
+	type Sleep = futures_timer::Delay;
+
+	fn sleep_unboxed( &self, dur: Duration ) -> Self::Sleep
+	{
+		FuturesTimer.sleep_unboxed( dur )
+	}
+}
+
+
+
+forward_traits!( TimerOnly: BlockOn, Shutdown, YieldNow, TokioIo, SpawnBlocking );
@@ -1,6 +1,7 @@
 use
 {
 	crate        :: { SpawnHandle, LocalSpawnHandle, JoinHandle, BlockingHandle            } ,
+	super        :: { tokio_spawn::RuntimeWatch                                            } ,
 	std          :: { fmt, rc::Rc, future::Future, convert::TryFrom                        } ,
 	tokio        :: { task::LocalSet, runtime::{ Builder, Runtime, Handle, RuntimeFlavor } } ,
 	futures_task :: { FutureObj, LocalFutureObj, Spawn, LocalSpawn, SpawnError             } ,
@@ -80,8 +81,12 @@ enum Spawner
 //
 pub struct TokioCt
 {
-	spawner: Spawner,
-	local: Rc< LocalSet > ,
+	spawner: Spawner                   ,
+	local  : Rc< LocalSet >             ,
+
+	// Only when created from a Handle. When we own the runtime, it can't have shut down.
+	//
+	watch  : Option< Rc<RuntimeWatch> > ,
 }
 
 
@@ -102,7 +107,8 @@ impl TryFrom<Runtime> for TokioCt
 			RuntimeFlavor::CurrentThread => Ok( Self
 			{
 				spawner: Spawner::Runtime( Rc::new(rt) ) ,
-				local: Rc::new( LocalSet::new() ) ,
+				local  : Rc::new( LocalSet::new() )      ,
+				watch  : None                            ,
 			}),
 
 			_ => Err( rt ),
@@ -126,8 +132,9 @@ impl TryFrom<Handle> for TokioCt
 		{
 			RuntimeFlavor::CurrentThread => Ok( Self
 			{
-				spawner: Spawner::Handle( handle ) ,
-				local: Rc::new( LocalSet::new() ) ,
+				watch  : Some( Rc::new( RuntimeWatch::new( &handle ) ) ) ,
+				spawner: Spawner::Handle( handle )                      ,
+				local  : Rc::new( LocalSet::new() )                     ,
 			}),
 
 			_ => Err( handle ),
@@ -161,7 +168,8 @@ impl TokioCt
 		Ok(Self
 		{
 			spawner: Spawner::Runtime(Rc::new( rt )),
-			local  : Rc::new( LocalSet::new() ) ,
+			local  : Rc::new( LocalSet::new() )     ,
+			watch  : None                           ,
 		})
 	}
 
@@ -246,6 +254,29 @@ impl TokioCt
 	{
 		self.local.run_until( f ).await
 	}
+
+
+
+	// A runtime we don't own can have shut down, see RuntimeWatch.
+	//
+	fn check_runtime( &self ) -> Result<(), SpawnError>
+	{
+		match &self.watch
+		{
+			Some( watch ) if watch.is_shutdown() => Err( SpawnError::shutdown() ),
+			_                                    => Ok(())                      ,
+		}
+	}
+}
+
+
+
+impl crate::BlockOn for TokioCt
+{
+	fn block_on<F: Future>( &self, future: F ) -> F::Output
+	{
+		TokioCt::block_on( self, future )
+	}
 }
 
 
@@ -254,9 +285,7 @@ impl Spawn for TokioCt
 {
 	fn spawn_obj( &self, future: FutureObj<'static, ()> ) -> Result<(), SpawnError>
 	{
-		// We drop the tokio JoinHandle, so the task becomes detached.
-		//
-		drop( self.local.spawn_local(future) );
+		self.spawn_handle_local_obj( future.into() )?.detach();
 
 		Ok(())
 	}
@@ -268,9 +297,7 @@ impl LocalSpawn for TokioCt
 {
 	fn spawn_local_obj( &self, future: LocalFutureObj<'static, ()> ) -> Result<(), SpawnError>
 	{
-		// We drop the tokio JoinHandle, so the task becomes detached.
-		//
-		drop( self.local.spawn_local(future) );
+		self.spawn_handle_local_obj( future )?.detach();
 
 		Ok(())
 	}
@@ -282,13 +309,37 @@ impl<Out: 'static + Send> SpawnHandle<Out> for TokioCt
 {
 	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
 	{
+		self.check_runtime()?;
+
 		let handle = match &self.spawner
 		{
 			Spawner::Runtime( rt     ) => rt    .spawn( future ) ,
 			Spawner::Handle ( handle ) => handle.spawn( future ) ,
 		};
 
-		Ok( JoinHandle::tokio(handle) )
+		Ok( JoinHandle::tokio( handle ) )
+	}
+
+
+	// Tokio only supports naming tasks with `--cfg tokio_unstable` and it's tracing feature.
+	//
+	#[ cfg(all( tokio_unstable, feature = "tracing" )) ]
+	//
+	fn spawn_handle_obj_named( &self, future: FutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let future  = crate::Named::new( future, name );
+		let builder = tokio::task::Builder::new().name( name );
+
+		self.check_runtime()?;
+
+		let handle = match &self.spawner
+		{
+			Spawner::Runtime( rt     ) => builder.spawn_on( future, rt.handle() ) ,
+			Spawner::Handle ( handle ) => builder.spawn_on( future, handle      ) ,
+
+		}.map_err( |_| SpawnError::shutdown() )?;
+
+		Ok( JoinHandle::tokio( handle ) )
 	}
 }
 
@@ -298,10 +349,26 @@ impl<Out: 'static> LocalSpawnHandle<Out> for TokioCt
 {
 	fn spawn_handle_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
 	{
-		let handle = self.local.spawn_local( future );
+		self.check_runtime()?;
+
+		Ok( JoinHandle::tokio( self.local.spawn_local( future ) ) )
+	}
+
+
+	// Tokio only supports naming tasks with `--cfg tokio_unstable` and it's tracing feature.
+	//
+	#[ cfg(all( tokio_unstable, feature = "tracing" )) ]
+	//
+	fn spawn_handle_local_obj_named( &self, future: LocalFutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.check_runtime()?;
 
-		Ok( JoinHandle::tokio(handle) )
+		let future = crate::Named::new( future, name );
 
+		let handle = tokio::task::Builder::new().name( name ).spawn_local_on( future, &self.local )
+			.map_err( |_| SpawnError::shutdown() )?;
+
+		Ok( JoinHandle::tokio( handle ) )
 	}
 }
 
@@ -331,6 +398,50 @@ impl crate::Timer for TokioCt
 	{
 		Box::pin( tokio::time::sleep(dur) )
 	}
+
+
+	fn sleep_until( &self, deadline: std::time::Instant ) -> futures_core::future::BoxFuture<'static, ()>
+	{
+		Box::pin( tokio::time::sleep_until( deadline.into() ) )
+	}
+}
+
+
+
+#[ cfg(all( feature = "timer", not(feature="tokio_timer" )) ) ]
+//
+#[ cfg_attr( nightly, doc(cfg(all( feature = "timer", feature = "tokio_ct" ))) ) ]
+//
+impl crate::UnboxedTimer for TokioCt
+{
+	type Sleep = futures_timer::Delay;
+
+	fn sleep_unboxed( &self, dur: std::time::Duration ) -> Self::Sleep
+	{
+		futures_timer::Delay::new( dur )
+	}
+}
+
+
+
+#[ cfg( feature = "tokio_timer" ) ]
+//
+#[ cfg_attr( nightly, doc(cfg(all( feature = "tokio_timer", feature = "tokio_ct" ))) ) ]
+//
+impl crate::UnboxedTimer for TokioCt
+{
+	type Sleep = tokio::time::Sleep;
+
+	fn sleep_unboxed( &self, dur: std::time::Duration ) -> Self::Sleep
+	{
+		tokio::time::sleep( dur )
+	}
+
+
+	fn sleep_until_unboxed( &self, deadline: std::time::Instant ) -> Self::Sleep
+	{
+		tokio::time::sleep_until( deadline.into() )
+	}
 }
 
 
@@ -0,0 +1,67 @@
+use
+{
+	std :: { sync::{ Arc, atomic::{ AtomicBool, Ordering } } } ,
+};
+
+
+/// Tells whether the runtime behind a [`Handle`](tokio::runtime::Handle) has shut down. Tokio accepts tasks
+/// after that, but cancels them right away, and a `LocalSet` accepts them regardless of the runtime.
+///
+/// It keeps a task on the runtime, which is dropped when the runtime shuts down. The task is aborted when
+/// this is dropped. Spawning only has to check a flag.
+//
+#[ derive( Debug ) ]
+//
+pub(crate) struct RuntimeWatch
+{
+	gone: Arc< AtomicBool >        ,
+	task: tokio::task::AbortHandle ,
+}
+
+
+impl RuntimeWatch
+{
+	pub(crate) fn new( handle: &tokio::runtime::Handle ) -> Self
+	{
+		let gone  = Arc::new( AtomicBool::new( false ) );
+		let guard = SetOnDrop( gone.clone() );
+
+		let task = handle.spawn( async move
+		{
+			let _guard = guard;
+			std::future::pending::<()>().await;
+
+		}).abort_handle();
+
+		Self{ gone, task }
+	}
+
+
+	pub(crate) fn is_shutdown( &self ) -> bool
+	{
+		self.gone.load( Ordering::Acquire )
+	}
+}
+
+
+impl Drop for RuntimeWatch
+{
+	fn drop( &mut self )
+	{
+		self.task.abort();
+	}
+}
+
+
+#[ derive( Debug ) ]
+//
+struct SetOnDrop( Arc<AtomicBool> );
+
+
+impl Drop for SetOnDrop
+{
+	fn drop( &mut self )
+	{
+		self.0.store( true, Ordering::Release );
+	}
+}
@@ -2,10 +2,11 @@
 //
 use
 {
-	crate          :: { SpawnHandle, JoinHandle, BlockingHandle          } ,
-	std            :: { fmt, sync::Arc, future::Future, convert::TryFrom } ,
-	futures_task   :: { FutureObj, Spawn, SpawnError                     } ,
-	tokio::runtime :: { Runtime, RuntimeFlavor, Handle, Builder          } ,
+	crate          :: { SpawnHandle, JoinHandle, BlockingHandle                        } ,
+	super          :: { tokio_spawn::RuntimeWatch                                      } ,
+	std            :: { fmt, sync::Arc, future::Future, convert::TryFrom, time::Duration } ,
+	futures_task   :: { FutureObj, Spawn, SpawnError                                   } ,
+	tokio::runtime :: { Runtime, RuntimeFlavor, Handle, Builder                        } ,
 };
 
 
@@ -83,7 +84,11 @@ use
 //
 pub struct TokioTp
 {
-	spawner: Spawner,
+	spawner: Spawner                    ,
+
+	// Only when created from a Handle. When we own the runtime, it can't have shut down.
+	//
+	watch  : Option< Arc<RuntimeWatch> > ,
 }
 
 
@@ -112,6 +117,7 @@ impl TryFrom<Runtime> for TokioTp
 			RuntimeFlavor::MultiThread => Ok( Self
 			{
 				spawner: Spawner::Runtime( Arc::new(rt) ) ,
+				watch  : None                             ,
 			}),
 
 			_ => Err( rt ),
@@ -136,7 +142,8 @@ impl TryFrom<Handle> for TokioTp
 		{
 			RuntimeFlavor::MultiThread => Ok( Self
 			{
-				spawner: Spawner::Handle( handle ) ,
+				watch  : Some( Arc::new( RuntimeWatch::new( &handle ) ) ) ,
+				spawner: Spawner::Handle( handle )                       ,
 			}),
 
 			_ => Err( handle ),
@@ -170,6 +177,7 @@ impl TokioTp
 		Ok(Self
 		{
 			spawner: Spawner::Runtime(Arc::new( rt )),
+			watch  : None                            ,
 		})
 	}
 
@@ -238,13 +246,13 @@ impl TokioTp
 	///  - [`TokioTpErr::Cloned`]: if the the [`TokioTp`] has been cloned. You can only shut down the last one.
 	///  - [`TokioTpErr::Handle`]: if the the [`TokioTp`] has been created from a handle. That is we don't own the [`Runtime`].
 	//
-	pub fn shutdown_timeout( self, duration: std::time::Duration ) -> Result<(), TokioTpErr>
+	pub fn shutdown_timeout( self, duration: Duration ) -> Result<(), TokioTpErr>
 	{
-		let Self{ spawner } = self;
+		let Self{ spawner, watch } = self;
 
 		let arc = match spawner
 		{
-			Spawner::Handle ( handle ) => return Err( TokioTpErr::Handle(Self{ spawner: Spawner::Handle(handle) }) ) ,
+			Spawner::Handle ( handle ) => return Err( TokioTpErr::Handle(Self{ spawner: Spawner::Handle(handle), watch }) ) ,
 			Spawner::Runtime( arc    ) => arc,
 		};
 
@@ -254,7 +262,7 @@ impl TokioTp
 			Ok(rt) => rt,
 			Err(arc) =>
 			{
-				let this = Self{ spawner: Spawner::Runtime(arc) };
+				let this = Self{ spawner: Spawner::Runtime(arc), watch };
 				return Err( TokioTpErr::Cloned(this) );
 			}
 		};
@@ -279,11 +287,11 @@ impl TokioTp
 	//
 	pub fn shutdown_background( self ) -> Result<(), TokioTpErr>
 	{
-		let Self{ spawner } = self;
+		let Self{ spawner, watch } = self;
 
 		let arc = match spawner
 		{
-			Spawner::Handle ( handle ) => return Err( TokioTpErr::Handle(Self{ spawner: Spawner::Handle(handle) }) ) ,
+			Spawner::Handle ( handle ) => return Err( TokioTpErr::Handle(Self{ spawner: Spawner::Handle(handle), watch }) ) ,
 			Spawner::Runtime( arc    ) => arc,
 		};
 
@@ -293,7 +301,7 @@ impl TokioTp
 			Ok(rt) => rt,
 			Err(arc) =>
 			{
-				let this = Self{ spawner: Spawner::Runtime(arc) };
+				let this = Self{ spawner: Spawner::Runtime(arc), watch };
 				return Err( TokioTpErr::Cloned(this) );
 			}
 		};
@@ -303,6 +311,29 @@ impl TokioTp
 
 		Ok(())
 	}
+
+
+
+	// A runtime we don't own can have shut down, see RuntimeWatch.
+	//
+	fn check_runtime( &self ) -> Result<(), SpawnError>
+	{
+		match &self.watch
+		{
+			Some( watch ) if watch.is_shutdown() => Err( SpawnError::shutdown() ),
+			_                                    => Ok(())                      ,
+		}
+	}
+}
+
+
+
+impl crate::BlockOn for TokioTp
+{
+	fn block_on<F: Future>( &self, future: F ) -> F::Output
+	{
+		TokioTp::block_on( self, future )
+	}
 }
 
 
@@ -315,16 +346,9 @@ impl crate::TokioIo for TokioTp {}
 
 impl Spawn for TokioTp
 {
-	/// Never fails.
 	fn spawn_obj( &self, future: FutureObj<'static, ()> ) -> Result<(), SpawnError>
 	{
-		// We drop the JoinHandle, so the task becomes detached.
-		//
-		match &self.spawner
-		{
-			Spawner::Runtime( rt     ) => drop( rt    .spawn(future) ) ,
-			Spawner::Handle ( handle ) => drop( handle.spawn(future) ) ,
-		}
+		self.spawn_handle_obj( future )?.detach();
 
 		Ok(())
 	}
@@ -336,13 +360,37 @@ impl<Out: 'static + Send> SpawnHandle<Out> for TokioTp
 {
 	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
 	{
+		self.check_runtime()?;
+
 		let handle = match &self.spawner
 		{
-			Spawner::Runtime( rt     ) => rt    .spawn(future) ,
-			Spawner::Handle ( handle ) => handle.spawn(future) ,
+			Spawner::Runtime( rt     ) => rt    .spawn( future ) ,
+			Spawner::Handle ( handle ) => handle.spawn( future ) ,
 		};
 
-		Ok( JoinHandle::tokio(handle) )
+		Ok( JoinHandle::tokio( handle ) )
+	}
+
+
+	// Tokio only supports naming tasks with `--cfg tokio_unstable` and it's tracing feature.
+	//
+	#[ cfg(all( tokio_unstable, feature = "tracing" )) ]
+	//
+	fn spawn_handle_obj_named( &self, future: FutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let future  = crate::Named::new( future, name );
+		let builder = tokio::task::Builder::new().name( name );
+
+		self.check_runtime()?;
+
+		let handle = match &self.spawner
+		{
+			Spawner::Runtime( rt     ) => builder.spawn_on( future, rt.handle() ) ,
+			Spawner::Handle ( handle ) => builder.spawn_on( future, handle      ) ,
+
+		}.map_err( |_| SpawnError::shutdown() )?;
+
+		Ok( JoinHandle::tokio( handle ) )
 	}
 }
 
@@ -383,7 +431,7 @@ impl<R: Send + 'static> crate::SpawnBlocking<R> for TokioTp
 //
 impl crate::Timer for TokioTp
 {
-	fn sleep( &self, dur: std::time::Duration ) -> futures_core::future::BoxFuture<'static, ()>
+	fn sleep( &self, dur: Duration ) -> futures_core::future::BoxFuture<'static, ()>
 	{
 		Box::pin( futures_timer::Delay::new(dur) )
 	}
@@ -397,10 +445,54 @@ impl crate::Timer for TokioTp
 //
 impl crate::Timer for TokioTp
 {
-	fn sleep( &self, dur: std::time::Duration ) -> futures_core::future::BoxFuture<'static, ()>
+	fn sleep( &self, dur: Duration ) -> futures_core::future::BoxFuture<'static, ()>
 	{
 		Box::pin( tokio::time::sleep(dur) )
 	}
+
+
+	fn sleep_until( &self, deadline: std::time::Instant ) -> futures_core::future::BoxFuture<'static, ()>
+	{
+		Box::pin( tokio::time::sleep_until( deadline.into() ) )
+	}
+}
+
+
+
+#[ cfg(all( feature = "timer", not(feature="tokio_timer" )) ) ]
+//
+#[ cfg_attr( nightly, doc(cfg(all( feature = "timer", feature = "tokio_tp" ))) ) ]
+//
+impl crate::UnboxedTimer for TokioTp
+{
+	type Sleep = futures_timer::Delay;
+
+	fn sleep_unboxed( &self, dur: Duration ) -> Self::Sleep
+	{
+		futures_timer::Delay::new( dur )
+	}
+}
+
+
+
+#[ cfg( feature = "tokio_timer" ) ]
+//
+#[ cfg_attr( nightly, doc(cfg(all( feature = "tokio_timer", feature = "tokio_tp" ))) ) ]
+//
+impl crate::UnboxedTimer for TokioTp
+{
+	type Sleep = tokio::time::Sleep;
+
+	fn sleep_unboxed( &self, dur: Duration ) -> Self::Sleep
+	{
+		tokio::time::sleep( dur )
+	}
+
+
+	fn sleep_until_unboxed( &self, deadline: std::time::Instant ) -> Self::Sleep
+	{
+		tokio::time::sleep_until( deadline.into() )
+	}
 }
 
 
@@ -6,6 +6,7 @@ use
 	futures_task    :: { SpawnError, LocalFutureObj, FutureObj  } ,
 	crate::iface    :: { *                                      } ,
 	tracing_futures :: { Instrument, Instrumented, WithDispatch } ,
+	tracing_crate   :: { info_span                              } ,
 };
 
 
@@ -18,6 +19,17 @@ impl<T, Out> SpawnHandle<Out> for Instrumented<T> where T: SpawnHandle<Out>, Out
 
 		self.inner().spawn_handle_obj( FutureObj::new(fut.boxed()) )
 	}
+
+
+	/// The future is instrumented with a child span named "task" which records the name of the task.
+	//
+	fn spawn_handle_obj_named( &self, future: FutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let task = info_span!( parent: self.span(), "task", name );
+		let fut  = future.instrument( task ).instrument( self.span().clone() );
+
+		self.inner().spawn_handle_obj_named( FutureObj::new(fut.boxed()), name )
+	}
 }
 
 
@@ -30,6 +42,14 @@ impl<T, Out> SpawnHandle<Out> for WithDispatch<T> where T: SpawnHandle<Out>, Out
 
 		self.inner().spawn_handle_obj( FutureObj::new(fut.boxed()) )
 	}
+
+
+	fn spawn_handle_obj_named( &self, future: FutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let fut = self.with_dispatch( future );
+
+		self.inner().spawn_handle_obj_named( FutureObj::new(fut.boxed()), name )
+	}
 }
 
 
@@ -42,6 +62,17 @@ impl<T, Out> LocalSpawnHandle<Out> for Instrumented<T> where T: LocalSpawnHandle
 
 		self.inner().spawn_handle_local_obj( LocalFutureObj::new(fut.boxed_local()) )
 	}
+
+
+	/// The future is instrumented with a child span named "task" which records the name of the task.
+	//
+	fn spawn_handle_local_obj_named( &self, future: LocalFutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let task = info_span!( parent: self.span(), "task", name );
+		let fut  = future.instrument( task ).instrument( self.span().clone() );
+
+		self.inner().spawn_handle_local_obj_named( LocalFutureObj::new(fut.boxed_local()), name )
+	}
 }
 
 
@@ -54,6 +85,14 @@ impl<T, Out> LocalSpawnHandle<Out> for WithDispatch<T> where T: LocalSpawnHandle
 
 		self.inner().spawn_handle_local_obj( LocalFutureObj::new(fut.boxed_local()) )
 	}
+
+
+	fn spawn_handle_local_obj_named( &self, future: LocalFutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let fut = self.with_dispatch(future);
+
+		self.inner().spawn_handle_local_obj_named( LocalFutureObj::new(fut.boxed_local()), name )
+	}
 }
 
 
@@ -64,6 +103,36 @@ impl<T> Timer for Instrumented<T> where T: Timer
 	{
 		self.inner().sleep( dur ).instrument( self.span().clone() ).boxed()
 	}
+
+
+	fn sleep_until( &self, deadline: std::time::Instant ) -> futures_core::future::BoxFuture<'static, ()>
+	{
+		self.inner().sleep_until( deadline ).instrument( self.span().clone() ).boxed()
+	}
+
+
+	fn current_time( &self ) -> Option<std::time::Instant>
+	{
+		self.inner().current_time()
+	}
+}
+
+
+
+impl<T> UnboxedTimer for Instrumented<T> where T: UnboxedTimer
+{
+	type Sleep = Instrumented<T::Sleep>;
+
+	fn sleep_unboxed( &self, dur: std::time::Duration ) -> Self::Sleep
+	{
+		self.inner().sleep_unboxed( dur ).instrument( self.span().clone() )
+	}
+
+
+	fn sleep_until_unboxed( &self, deadline: std::time::Instant ) -> Self::Sleep
+	{
+		self.inner().sleep_until_unboxed( deadline ).instrument( self.span().clone() )
+	}
 }
 
 
@@ -74,6 +143,55 @@ impl<T> Timer for WithDispatch<T> where T: Timer
 	{
 		self.with_dispatch( self.inner().sleep( dur ) ).boxed()
 	}
+
+
+	fn sleep_until( &self, deadline: std::time::Instant ) -> futures_core::future::BoxFuture<'static, ()>
+	{
+		self.with_dispatch( self.inner().sleep_until( deadline ) ).boxed()
+	}
+
+
+	fn current_time( &self ) -> Option<std::time::Instant>
+	{
+		self.inner().current_time()
+	}
+}
+
+
+
+impl<T> UnboxedTimer for WithDispatch<T> where T: UnboxedTimer
+{
+	type Sleep = WithDispatch<T::Sleep>;
+
+	fn sleep_unboxed( &self, dur: std::time::Duration ) -> Self::Sleep
+	{
+		self.with_dispatch( self.inner().sleep_unboxed( dur ) )
+	}
+
+
+	fn sleep_until_unboxed( &self, deadline: std::time::Instant ) -> Self::Sleep
+	{
+		self.with_dispatch( self.inner().sleep_until_unboxed( deadline ) )
+	}
+}
+
+
+impl<T> BlockOn for Instrumented<T> where T: BlockOn
+{
+	fn block_on<F: std::future::Future>( &self, future: F ) -> F::Output
+	{
+		self.inner().block_on( future.instrument( self.span().clone() ) )
+	}
+}
+
+
+
+impl<T> BlockOn for WithDispatch<T> where T: BlockOn
+{
+	fn block_on<F: std::future::Future>( &self, future: F ) -> F::Output
+	{
+		self.inner().block_on( self.with_dispatch( future ) )
+	}
 }
 
 
@@ -0,0 +1,200 @@
+use
+{
+	crate::iface :: { *                                                         } ,
+	futures_task :: { SpawnError, FutureObj, LocalFutureObj, Spawn, LocalSpawn } ,
+	futures_core :: { future::BoxFuture                                         } ,
+	futures_util :: { future::FutureExt                                         } ,
+	std          :: { time::Duration                                            } ,
+};
+
+
+/// An executor wrapper that counts the tasks spawned through it which are still alive. This lets you
+/// wait for everything that was spawned to finish, even on executors that don't offer a way to do so,
+/// like [`AsyncStd`](crate::AsyncStd) and [`AsyncGlobal`](crate::AsyncGlobal).
+///
+/// A task counts as alive until the executor drops it's future, that is when it has completed or has
+/// been aborted. Clones of a `Tracked` share the same count. Closures passed to [`SpawnBlocking`] are
+/// not counted.
+///
+/// When the wrapped executor has a [`Timer`], this also implements [`Shutdown`], which refuses new tasks
+/// and aborts the ones that don't finish in time. Only tasks spawned through the wrapper are affected.
+///
+/// ```
+/// use async_executors::{ Tracked, SpawnHandle, SpawnHandleExt };
+///
+/// async fn run( exec: impl SpawnHandle<()> )
+/// {
+///    let exec = Tracked::new( exec );
+///
+///    exec.spawn_handle( async { /* ... */ } ).expect( "spawn" ).detach();
+///
+///    // Resolves once the detached task has finished.
+///    //
+///    exec.wait_idle().await;
+///
+///    assert_eq!( exec.active_tasks(), 0 );
+/// }
+/// ```
+//
+#[ derive( Debug, Clone ) ]
+//
+pub struct Tracked<E>
+{
+	exec   : E           ,
+	tracker: TaskTracker ,
+}
+
+
+
+impl<E> Tracked<E>
+{
+	/// Wrap an executor.
+	//
+	pub fn new( exec: E ) -> Self
+	{
+		Self{ exec, tracker: TaskTracker::default() }
+	}
+
+
+	/// The number of tasks spawned through this wrapper, or it's clones, which have not finished yet.
+	//
+	pub fn active_tasks( &self ) -> usize
+	{
+		self.tracker.len()
+	}
+
+
+	/// Resolves when no tasks spawned through this wrapper, or it's clones, are alive. Tasks spawned
+	/// while waiting are waited for as well.
+	//
+	#[ must_use = "wait_idle() returns a future, which does nothing unless awaited" ]
+	//
+	pub fn wait_idle( &self ) -> BoxFuture<'static, ()>
+	{
+		self.tracker.idle().boxed()
+	}
+
+
+	/// Access the wrapped executor.
+	//
+	pub fn inner( &self ) -> &E
+	{
+		&self.exec
+	}
+
+
+	/// Unwrap the executor.
+	//
+	pub fn into_inner( self ) -> E
+	{
+		self.exec
+	}
+}
+
+
+
+/// Returns [`SpawnError::shutdown`] after [`Shutdown::shutdown`] has been called.
+//
+impl<E> Spawn for Tracked<E> where E: Spawn
+{
+	fn spawn_obj( &self, future: FutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		let fut            = self.tracker.track( future )?;
+		let (task, handle) = AbortHandle::wrap_keep_id( fut );
+
+		self.exec.spawn_obj( FutureObj::new( task.map( drop ).boxed() ) )?;
+		self.tracker.register( handle );
+
+		Ok(())
+	}
+}
+
+
+
+/// Returns [`SpawnError::shutdown`] after [`Shutdown::shutdown`] has been called.
+//
+impl<E> LocalSpawn for Tracked<E> where E: LocalSpawn
+{
+	fn spawn_local_obj( &self, future: LocalFutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		let fut            = self.tracker.track( future )?;
+		let (task, handle) = AbortHandle::wrap_keep_id( fut );
+
+		self.exec.spawn_local_obj( LocalFutureObj::new( task.map( drop ).boxed_local() ) )?;
+		self.tracker.register( handle );
+
+		Ok(())
+	}
+}
+
+
+
+/// Returns [`SpawnError::shutdown`] after [`Shutdown::shutdown`] has been called.
+//
+impl<E, Out> SpawnHandle<Out> for Tracked<E> where E: SpawnHandle<Out>, Out: 'static + Send
+{
+	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let fut    = self.tracker.track( future )?;
+		let handle = self.exec.spawn_handle_obj( FutureObj::new(fut.boxed()) )?;
+
+		self.tracker.register( handle.abort_handle() );
+
+		Ok( handle )
+	}
+
+
+	fn spawn_handle_obj_named( &self, future: FutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let fut    = self.tracker.track( future )?;
+		let handle = self.exec.spawn_handle_obj_named( FutureObj::new(fut.boxed()), name )?;
+
+		self.tracker.register( handle.abort_handle() );
+
+		Ok( handle )
+	}
+}
+
+
+
+/// Returns [`SpawnError::shutdown`] after [`Shutdown::shutdown`] has been called.
+//
+impl<E, Out> LocalSpawnHandle<Out> for Tracked<E> where E: LocalSpawnHandle<Out>, Out: 'static
+{
+	fn spawn_handle_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let fut    = self.tracker.track( future )?;
+		let handle = self.exec.spawn_handle_local_obj( LocalFutureObj::new(fut.boxed_local()) )?;
+
+		self.tracker.register( handle.abort_handle() );
+
+		Ok( handle )
+	}
+
+
+	fn spawn_handle_local_obj_named( &self, future: LocalFutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		let fut    = self.tracker.track( future )?;
+		let handle = self.exec.spawn_handle_local_obj_named( LocalFutureObj::new(fut.boxed_local()), name )?;
+
+		self.tracker.register( handle.abort_handle() );
+
+		Ok( handle )
+	}
+}
+
+
+
+/// Await the shutdown from within [`BlockOn::block_on`] on the single threaded executors, see [`Shutdown`].
+//
+impl<E> Shutdown for Tracked<E> where E: Timer
+{
+	fn shutdown( &self, timeout: Duration ) -> BoxFuture<'static, ShutdownReport>
+	{
+		self.tracker.shutdown( self.exec.sleep( timeout ) )
+	}
+}
+
+
+
+forward_traits!( Tracked: BlockOn, Timer, UnboxedTimer, YieldNow, TokioIo, SpawnBlocking );
@@ -0,0 +1,302 @@
+use
+{
+	crate::iface :: { *                                                         } ,
+	futures_task :: { SpawnError, FutureObj, LocalFutureObj, Spawn, LocalSpawn  } ,
+	futures_util :: { future::FutureExt                                         } ,
+	pin_project  :: { pin_project                                               } ,
+	std          :: { fmt, future::Future, panic::Location, pin::Pin, sync::Arc } ,
+	std          :: { task::{ Context, Poll }, time::Duration                   } ,
+};
+
+
+/// An executor wrapper that times every poll of the tasks spawned through it and reports the ones that take
+/// longer than a threshold. A poll that takes long means the task blocks the thread of the executor, which
+/// delays all other tasks on that thread.
+///
+/// Reports go to a callback, or with the `tracing` feature, can be emitted as `tracing` events. The
+/// callback runs on the executor thread, right after the slow poll, so it should not block either.
+///
+/// The spawn location is recorded with `#[track_caller]`. It points at your code when spawning through
+/// [`SpawnHandleExt`] and [`LocalSpawnHandleExt`] on the watchdog itself. When spawning through
+/// `SpawnExt` from _futures_, or through a reference or smart pointer to the watchdog, it points at the
+/// code that forwards the call.
+///
+/// On Wasm there is no clock in std, so nothing gets reported.
+///
+/// ```
+/// use async_executors::{ Watchdog, SpawnHandle, SpawnHandleExt };
+/// use std::time::Duration;
+///
+/// async fn run( exec: impl SpawnHandle<()> )
+/// {
+///    let exec = Watchdog::new( exec, Duration::from_millis( 10 ), |slow|
+///    {
+///       eprintln!( "task {:?} blocked for {:?}, spawned at {}", slow.task_name, slow.duration, slow.location );
+///    });
+///
+///    exec.spawn_handle( async { std::thread::sleep( Duration::from_millis( 50 ) ) } ).expect( "spawn" ).await;
+/// }
+/// ```
+//
+#[ derive( Debug, Clone ) ]
+//
+pub struct Watchdog<E>
+{
+	exec     : E        ,
+	threshold: Duration ,
+	report   : Report   ,
+}
+
+
+
+/// A poll that took longer than the threshold of a [`Watchdog`].
+//
+#[ derive( Debug, Clone ) ]
+//
+pub struct SlowPoll
+{
+	/// How long the poll took.
+	//
+	pub duration: Duration,
+
+	/// The id of the task, if the executor provides one. See [`task_id`].
+	//
+	pub task_id: Option<TaskId>,
+
+	/// The name of the task, if it was spawned with a name.
+	//
+	pub task_name: Option< Arc<str> >,
+
+	/// Where the task was spawned.
+	//
+	pub location: &'static Location<'static>,
+}
+
+
+
+#[ derive( Clone ) ]
+//
+enum Report
+{
+	Callback( Arc< dyn Fn( &SlowPoll ) + Send + Sync > ),
+
+	#[ cfg( feature = "tracing" ) ]
+	//
+	Tracing,
+}
+
+
+impl Report
+{
+	fn report( &self, slow: &SlowPoll )
+	{
+		match self
+		{
+			Self::Callback( callback ) => callback( slow ),
+
+			#[ cfg( feature = "tracing" ) ]
+			//
+			Self::Tracing => tracing_crate::warn!
+			(
+				duration  = ?slow.duration                                       ,
+				task_id   = slow.task_id.map( tracing_crate::field::display )    ,
+				task_name = slow.task_name.as_deref()                            ,
+				location  = %slow.location                                       ,
+				"slow poll"
+			),
+		}
+	}
+}
+
+
+impl fmt::Debug for Report
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		match self
+		{
+			Self::Callback(_) => f.write_str( "Report::Callback" ),
+
+			#[ cfg( feature = "tracing" ) ]
+			//
+			Self::Tracing => f.write_str( "Report::Tracing" ),
+		}
+	}
+}
+
+
+
+impl<E> Watchdog<E>
+{
+	/// Wrap an executor, calling `callback` for every poll that takes longer than `threshold`.
+	//
+	pub fn new( exec: E, threshold: Duration, callback: impl Fn( &SlowPoll ) + Send + Sync + 'static ) -> Self
+	{
+		Self{ exec, threshold, report: Report::Callback( Arc::new(callback) ) }
+	}
+
+
+	/// Wrap an executor, emitting a `tracing` event at the warn level for every poll that takes longer
+	/// than `threshold`.
+	//
+	#[ cfg( feature = "tracing" ) ]
+	//
+	#[ cfg_attr( nightly, doc(cfg( feature = "tracing" )) ) ]
+	//
+	pub fn tracing( exec: E, threshold: Duration ) -> Self
+	{
+		Self{ exec, threshold, report: Report::Tracing }
+	}
+
+
+	/// The threshold above which polls are reported.
+	//
+	pub fn threshold( &self ) -> Duration
+	{
+		self.threshold
+	}
+
+
+	/// Access the wrapped executor.
+	//
+	pub fn inner( &self ) -> &E
+	{
+		&self.exec
+	}
+
+
+	/// Unwrap the executor.
+	//
+	pub fn into_inner( self ) -> E
+	{
+		self.exec
+	}
+
+
+	#[ track_caller ]
+	//
+	fn watch<F: Future>( &self, future: F, name: Option<&str> ) -> Watched<F>
+	{
+		Watched
+		{
+			future,
+			threshold: self.threshold        ,
+			report   : self.report.clone()   ,
+			name     : name.map( Arc::from ) ,
+			location : Location::caller()    ,
+		}
+	}
+}
+
+
+
+#[ pin_project ]
+//
+#[ derive( Debug ) ]
+//
+struct Watched<F>
+{
+	#[pin] future   : F                          ,
+	       threshold: Duration                   ,
+	       report   : Report                     ,
+	       name     : Option< Arc<str> >         ,
+	       location : &'static Location<'static> ,
+}
+
+
+impl<F: Future> Future for Watched<F>
+{
+	type Output = F::Output;
+
+	fn poll( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Self::Output>
+	{
+		let this  = self.project();
+		let start = now();
+		let poll  = this.future.poll( cx );
+
+		let Some( duration ) = start.map( |s| s.elapsed() ) else { return poll };
+
+		if duration > *this.threshold
+		{
+			// We are still inside the task, so the executor has set it's id and name.
+			//
+			this.report.report( &SlowPoll
+			{
+				duration                                          ,
+				task_id  : task_id()                              ,
+				task_name: this.name.clone().or_else( task_name ) ,
+				location : this.location                          ,
+			});
+		}
+
+		poll
+	}
+}
+
+
+
+impl<E> Spawn for Watchdog<E> where E: Spawn
+{
+	#[ track_caller ]
+	//
+	fn spawn_obj( &self, future: FutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		self.exec.spawn_obj( FutureObj::new( self.watch( future, None ).boxed() ) )
+	}
+}
+
+
+
+impl<E> LocalSpawn for Watchdog<E> where E: LocalSpawn
+{
+	#[ track_caller ]
+	//
+	fn spawn_local_obj( &self, future: LocalFutureObj<'static, ()> ) -> Result<(), SpawnError>
+	{
+		self.exec.spawn_local_obj( LocalFutureObj::new( self.watch( future, None ).boxed_local() ) )
+	}
+}
+
+
+
+impl<E, Out> SpawnHandle<Out> for Watchdog<E> where E: SpawnHandle<Out>, Out: 'static + Send
+{
+	#[ track_caller ]
+	//
+	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.exec.spawn_handle_obj( FutureObj::new( self.watch( future, None ).boxed() ) )
+	}
+
+
+	#[ track_caller ]
+	//
+	fn spawn_handle_obj_named( &self, future: FutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.exec.spawn_handle_obj_named( FutureObj::new( self.watch( future, Some(name) ).boxed() ), name )
+	}
+}
+
+
+
+impl<E, Out> LocalSpawnHandle<Out> for Watchdog<E> where E: LocalSpawnHandle<Out>, Out: 'static
+{
+	#[ track_caller ]
+	//
+	fn spawn_handle_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.exec.spawn_handle_local_obj( LocalFutureObj::new( self.watch( future, None ).boxed_local() ) )
+	}
+
+
+	#[ track_caller ]
+	//
+	fn spawn_handle_local_obj_named( &self, future: LocalFutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.exec.spawn_handle_local_obj_named( LocalFutureObj::new( self.watch( future, Some(name) ).boxed_local() ), name )
+	}
+}
+
+
+
+forward_traits!( Watchdog );
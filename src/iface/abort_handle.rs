@@ -0,0 +1,222 @@
+#[ allow(unused_imports) ] // some imports are conditional on features
+//
+use
+{
+	std          :: { future::Future, pin::Pin, task::{ Poll, Context }                 } ,
+	std          :: { sync::{ Arc, atomic::{ AtomicBool, Ordering } }                    } ,
+	futures_util :: { future::{ Abortable, AbortHandle as FutAbortHandle, Aborted }, ready } ,
+	pin_project  :: { pin_project                                                       } ,
+	crate        :: { TaskId                                                            } ,
+};
+
+#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct" )) ]
+//
+use tokio::task::{ AbortHandle as TokioAbortHandle };
+
+
+
+/// A cloneable handle that can cancel a spawned task from elsewhere. You can obtain one
+/// with [`JoinHandle::abort_handle`](crate::JoinHandle::abort_handle).
+///
+/// As opposed to the [`JoinHandle`](crate::JoinHandle), dropping this does nothing.
+///
+/// Aborting is not instantaneous. The task will be dropped the next time the executor would poll it.
+/// Awaiting the [`JoinHandle`](crate::JoinHandle) of an aborted task with
+/// [`try_join`](crate::JoinHandle::try_join) returns [`JoinError::Cancelled`](crate::JoinError::Cancelled),
+/// unless the task has already completed.
+//
+#[ derive( Debug, Clone ) ]
+//
+pub struct AbortHandle
+{
+	inner: InnerAh,
+}
+
+
+
+#[ derive( Debug, Clone ) ]
+//
+enum InnerAh
+{
+	/// Wrapper around tokio AbortHandle.
+	//
+	#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct" )) ]
+	//
+	Tokio( TokioAbortHandle ),
+
+	/// Executors that have no native abort. The task is wrapped in an [`AbortableTask`].
+	//
+	Futures
+	{
+		handle  : FutAbortHandle  ,
+		finished: Arc<AtomicBool> ,
+		id      : TaskId          ,
+	},
+}
+
+
+
+impl AbortHandle
+{
+	/// Make a wrapper around [`tokio::task::AbortHandle`].
+	//
+	#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct" )) ]
+	//
+	pub fn tokio( handle: TokioAbortHandle ) -> Self
+	{
+		Self{ inner: InnerAh::Tokio( handle ) }
+	}
+
+
+
+	/// Wrap a future so the task can be canceled through the returned [`AbortHandle`], and so
+	/// the handle can tell whether the task has finished. This also gives the task a [`TaskId`].
+	/// This is meant for implementing [`SpawnHandle`](crate::SpawnHandle) on executors that can't
+	/// abort tasks natively.
+	//
+	pub fn wrap<F: Future>( future: F ) -> ( AbortableTask<F>, Self )
+	{
+		let (handle, reg) = FutAbortHandle::new_pair();
+		let finished      = Arc::new( AtomicBool::new( false ) );
+		let id            = TaskId::next();
+
+		let task = AbortableTask
+		{
+			future  : Abortable::new( future, reg )   ,
+			guard   : FinishGuard( finished.clone() ) ,
+			id                                        ,
+			enter_id: true                            ,
+		};
+
+		( task, Self{ inner: InnerAh::Futures{ handle, finished, id } } )
+	}
+
+
+
+	/// Like [`AbortHandle::wrap`], but the task keeps the id the executor gives it, if any. For wrappers
+	/// which make tasks abortable on top of another executor. The id of the returned handle is not the
+	/// one seen from within the task, so don't hand it out.
+	//
+	pub(crate) fn wrap_keep_id<F: Future>( future: F ) -> ( AbortableTask<F>, Self )
+	{
+		let (mut task, handle) = Self::wrap( future );
+
+		task.enter_id = false;
+
+		( task, handle )
+	}
+
+
+
+	/// Cancel the task. Has no effect if the task has already completed.
+	//
+	pub fn abort( &self )
+	{
+		match &self.inner
+		{
+			#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct" )) ]
+			//
+			InnerAh::Tokio( handle ) => handle.abort(),
+
+			InnerAh::Futures{ handle, .. } => handle.abort(),
+		}
+	}
+
+
+
+	/// Whether the task has finished. This is the case when it ran to completion, when it panicked and
+	/// when it was aborted and subsequently dropped by the executor.
+	//
+	pub fn is_finished( &self ) -> bool
+	{
+		match &self.inner
+		{
+			#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct" )) ]
+			//
+			InnerAh::Tokio( handle ) => handle.is_finished(),
+
+			InnerAh::Futures{ finished, .. } => finished.load( Ordering::Acquire ),
+		}
+	}
+
+
+
+	/// The id of the task this handle aborts.
+	//
+	pub fn id( &self ) -> TaskId
+	{
+		match &self.inner
+		{
+			#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct" )) ]
+			//
+			InnerAh::Tokio( handle ) => TaskId::tokio( handle.id() ),
+
+			InnerAh::Futures{ id, .. } => *id,
+		}
+	}
+}
+
+
+
+/// A future that can be canceled through an [`AbortHandle`]. Created with [`AbortHandle::wrap`].
+/// Resolves to `Err(Aborted)` when canceled.
+//
+#[ pin_project ]
+//
+#[ must_use = "futures do nothing unless polled" ]
+//
+#[ derive( Debug ) ]
+//
+pub struct AbortableTask<F>
+{
+	#[pin] future: Abortable<F>,
+
+	// Will flag the task as finished when it is dropped, even during unwinding.
+	//
+	guard: FinishGuard,
+
+	// Set as the current task id while polling, so `task_id` works from within the task.
+	//
+	id: TaskId,
+
+	// False when the executor provides the id of the task.
+	//
+	enter_id: bool,
+}
+
+
+
+impl<F: Future> Future for AbortableTask<F>
+{
+	type Output = Result< F::Output, Aborted >;
+
+	fn poll( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Self::Output>
+	{
+		let this   = self.project();
+		let future = this.future;
+		let out    = match this.enter_id
+		{
+			true  => ready!( this.id.enter( || future.poll( cx ) ) ),
+			false => ready!( future.poll( cx ) )                    ,
+		};
+
+		this.guard.0.store( true, Ordering::Release );
+
+		Poll::Ready( out )
+	}
+}
+
+
+
+#[ derive( Debug ) ]
+//
+struct FinishGuard( Arc<AtomicBool> );
+
+
+impl Drop for FinishGuard
+{
+	fn drop( &mut self )
+	{
+		self.0.store( true, Ordering::Release );
+	}
+}
@@ -0,0 +1,40 @@
+use
+{
+	std     :: { future::Future } ,
+	blanket :: { blanket        } ,
+};
+
+
+/// Run a future to completion on the current thread while driving the executor. This is the entry point
+/// of a program, or of a test, which allows writing code that is generic over the executor.
+///
+/// This is implemented by all executors except [`Bindgen`](crate::Bindgen), as you cannot block the only
+/// thread you have on Wasm. [`LocalSpawner`](crate::LocalSpawner) does not own it's pool, so use
+/// [`LocalPoolExec`](crate::LocalPoolExec) instead.
+///
+/// ```
+/// use async_executors::{ BlockOn, SpawnHandle, SpawnHandleExt };
+///
+/// fn run( exec: impl BlockOn + SpawnHandle<u8> ) -> u8
+/// {
+///    exec.block_on( async
+///    {
+///       exec.spawn_handle( async { 5 } ).expect( "spawn" ).await
+///    })
+/// }
+/// ```
+//
+#[ blanket( derive( Ref, Mut, Rc, Arc, Box ) ) ]
+//
+pub trait BlockOn
+{
+	/// Block the current thread until `future` completes. Tasks spawned on the executor will run
+	/// in the meantime.
+	///
+	/// # Panics
+	///
+	/// Most executors will panic if this is called from within async context, for example from a task
+	/// running on the same executor.
+	//
+	fn block_on<F: Future>( &self, future: F ) -> F::Output;
+}
@@ -9,6 +9,20 @@ use
 };
 
 
+// Executors for which `DynExec` runs blocking closures on the threadpool of the blocking crate.
+//
+#[ cfg(all( not(target_arch = "wasm32"), any( feature = "glommio", feature = "threadpool", feature = "localpool" ) )) ]
+//
+use blocking::Task as BlockingTask;
+
+
+// Executors that catch the panic of a blocking closure to resume it when the handle is awaited.
+//
+#[ cfg(any( all( not(target_arch = "wasm32"), any( feature = "glommio", feature = "threadpool", feature = "localpool" ) ), feature = "sim" )) ]
+//
+use std::panic::{ catch_unwind, resume_unwind, AssertUnwindSafe };
+
+
 #[ cfg( feature = "async_global" ) ]
 //
 type BoxedFut<T> = Pin<Box< dyn Future<Output=T> + Send >>;
@@ -54,6 +68,31 @@ impl<T> BlockingHandle<T>
 	{
 		Self( InnerBh::AsyncStd(handle) )
 	}
+
+
+	/// Run the closure on the threadpool of the [_blocking_](https://docs.rs/blocking) crate, for executors
+	/// that don't have a threadpool for blocking tasks. The pool grows on demand up to a limit, 500 threads
+	/// by default, after which closures wait for a free thread. It's shared with async-std and
+	/// async-global-executor.
+	//
+	#[ cfg(all( not(target_arch = "wasm32"), any( feature = "glommio", feature = "threadpool", feature = "localpool" ) )) ]
+	//
+	pub(crate) fn thread( f: impl FnOnce() -> T + Send + 'static ) -> Self
+
+		where T: Send + 'static
+	{
+		Self( InnerBh::Thread( blocking::unblock( move || catch_unwind( AssertUnwindSafe(f) ) ) ) )
+	}
+
+
+	/// Run the closure right away on the current thread, for the simulation executor.
+	//
+	#[ cfg( feature = "sim" ) ]
+	//
+	pub(crate) fn inline( f: impl FnOnce() -> T ) -> Self
+	{
+		Self( InnerBh::Inline( std::future::ready( catch_unwind( AssertUnwindSafe(f) ) ) ) )
+	}
 }
 
 
@@ -80,6 +119,18 @@ enum InnerBh<T>
 	//
 	AsyncStd( AsyncStdJoinHandle<T> ),
 
+	/// A task on the blocking threadpool. The panic payload is returned if the closure panicked.
+	//
+	#[ cfg(all( not(target_arch = "wasm32"), any( feature = "glommio", feature = "threadpool", feature = "localpool" ) )) ]
+	//
+	Thread( BlockingTask< std::thread::Result<T> > ),
+
+	/// The closure already ran. The panic payload is returned if the closure panicked.
+	//
+	#[ cfg( feature = "sim" ) ]
+	//
+	Inline( std::future::Ready< std::thread::Result<T> > ),
+
 	// Since the other variants are behind feature flags, the generic won't be
 	// used if we don't include this.
 	//
@@ -112,6 +163,22 @@ impl<T: 'static> Future for BlockingHandle<T>
 			#[ cfg( feature = "async_std"    ) ] InnerBh::AsyncStd   ( handle ) => Pin::new( handle ).poll( _cx ) ,
 			#[ cfg( feature = "async_global" ) ] InnerBh::AsyncGlobal( task   ) => Pin::new( task   ).poll( _cx ) ,
 
+			#[ cfg(all( not(target_arch = "wasm32"), any( feature = "glommio", feature = "threadpool", feature = "localpool" ) )) ]
+			//
+			InnerBh::Thread( task ) => match ready!( Pin::new( task ).poll( _cx ) )
+			{
+				Ok (t      ) => Poll::Ready( t ),
+				Err(payload) => resume_unwind( payload ),
+			},
+
+			#[ cfg( feature = "sim" ) ]
+			//
+			InnerBh::Inline( ready ) => match ready!( Pin::new( ready ).poll( _cx ) )
+			{
+				Ok (t      ) => Poll::Ready( t ),
+				Err(payload) => resume_unwind( payload ),
+			},
+
 			InnerBh::Phantom(_) => unreachable!(),
 		}
 	}
@@ -0,0 +1,87 @@
+use
+{
+	std::{ any::Any, fmt } ,
+};
+
+
+/// The reason a task did not run to completion. This is returned by [`TryJoin`](crate::TryJoin),
+/// which you get from [`JoinHandle::try_join`](crate::JoinHandle::try_join).
+//
+pub enum JoinError
+{
+	/// The task was canceled before it completed. This happens when the executor is dropped or shut down
+	/// while the task is still running.
+	//
+	Cancelled,
+
+	/// The task panicked. This holds the panic payload, which you can inspect or pass to
+	/// [`std::panic::resume_unwind`].
+	//
+	Panicked( Box< dyn Any + Send + 'static > ),
+}
+
+
+impl JoinError
+{
+	/// Whether the task was canceled.
+	//
+	pub fn is_cancelled( &self ) -> bool
+	{
+		matches!( self, JoinError::Cancelled )
+	}
+
+
+	/// Whether the task panicked.
+	//
+	pub fn is_panic( &self ) -> bool
+	{
+		matches!( self, JoinError::Panicked(_) )
+	}
+
+
+	/// Returns the panic message if the task panicked with a `&str` or a `String`, which is
+	/// what `panic!` generates.
+	//
+	pub fn panic_message( &self ) -> Option<&str>
+	{
+		match self
+		{
+			JoinError::Cancelled => None,
+
+			JoinError::Panicked( payload ) =>
+
+				payload.downcast_ref::<&str>().copied()
+					.or_else( || payload.downcast_ref::<String>().map( String::as_str ) ),
+		}
+	}
+}
+
+
+impl fmt::Debug for JoinError
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		match self
+		{
+			JoinError::Cancelled   => write!( f, "JoinError::Cancelled"      ),
+			JoinError::Panicked(_) => write!( f, "JoinError::Panicked(...)" ),
+		}
+	}
+}
+
+
+impl fmt::Display for JoinError
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		match ( self, self.panic_message() )
+		{
+			( JoinError::Cancelled, _ ) => write!( f, "The task was canceled before it completed." ),
+			( _, Some(msg)          )   => write!( f, "The task panicked: {msg}" ),
+			( _, None               )   => write!( f, "The task panicked." ),
+		}
+	}
+}
+
+
+impl std::error::Error for JoinError {}
@@ -4,7 +4,8 @@ use
 {
 	std         :: { future::Future, sync::atomic::{ AtomicBool, Ordering } } ,
 	std         :: { task::{ Poll, Context }, pin::Pin                      } ,
-	futures_util:: { future::{ AbortHandle, Aborted, RemoteHandle }, ready  } ,
+	std         :: { panic::{ catch_unwind, resume_unwind, AssertUnwindSafe } } ,
+	futures_util:: { future::{ Aborted, RemoteHandle }, ready                } ,
 	super :: *,
 };
 
@@ -22,12 +23,15 @@ use
 ///
 /// # Panics
 ///
-/// There is an inconsistency between executors when it comes to a panicking task.
-/// Generally we unwind the thread on which the handle is awaited when a task panics,
-/// but async-std will also let the executor working thread unwind. No `catch_unwind` was added to
-/// bring async-std in line with the other executors here.
+/// When a task spawned with a handle panics, the panic is resumed on the thread on which the handle is awaited,
+/// with the original payload. Tasks spawned without a handle behave differently per executor, async-std
+/// will let the executor working thread unwind. Wrap the executor in [`CatchUnwind`](crate::CatchUnwind)
+/// if you want the same behavior everywhere.
 ///
 /// Awaiting the JoinHandle can also panic if you drop the executor before it completes.
+///
+/// If you want to observe these situations rather than unwind the awaiting thread, use
+/// [`try_join`](JoinHandle::try_join), which returns a [`JoinError`] instead.
 //
 #[ derive( Debug ) ]
 //
@@ -53,29 +57,39 @@ impl<T> JoinHandle<T>
 
 
 
-	/// Make a wrapper around [`async_global_executor::Task`].
+	/// Make a wrapper around [`async_global_executor::Task`]. The task needs to catch panics with
+	/// [`FutureExt::catch_unwind`](futures_util::FutureExt::catch_unwind), so the payload can be returned
+	/// from [`try_join`](JoinHandle::try_join), and then be wrapped with [`AbortHandle::wrap`] so it can be
+	/// aborted without taking ownership of the `Task`.
 	//
 	#[ cfg( feature = "async_global" ) ]
 	//
-	pub fn async_global( task: AsyncGlobalTask<T> ) -> Self
+	pub fn async_global
+	(
+		task    : AsyncGlobalTask<Result<std::thread::Result<T>, Aborted>> ,
+		a_handle: AbortHandle                                              ,
+
+	) -> Self
 	{
 		let task  = Some( task );
-		let inner = InnerJh::AsyncGlobal{ task };
+		let inner = InnerJh::AsyncGlobal{ task, a_handle };
 
 		Self{ inner }
 	}
 
 
 
-	/// Make a wrapper around [`async_std::task::JoinHandle`](async_std_crate::task::JoinHandle). The task needs to
-	/// be wrapped in an abortable so we can cancel it on drop.
+	/// Make a wrapper around [`async_std::task::JoinHandle`](async_std_crate::task::JoinHandle). The task needs
+	/// to catch panics with [`FutureExt::catch_unwind`](futures_util::FutureExt::catch_unwind), so the payload
+	/// can be returned from [`try_join`](JoinHandle::try_join), and then be wrapped with [`AbortHandle::wrap`]
+	/// so we can cancel it on drop.
 	//
 	#[ cfg( feature = "async_std" ) ]
 	//
 	pub fn async_std
 	(
-		handle  : AsyncStdJoinHandle<Result<T, Aborted>> ,
-		a_handle: AbortHandle                            ,
+		handle  : AsyncStdJoinHandle<Result<std::thread::Result<T>, Aborted>> ,
+		a_handle: AbortHandle                                                 ,
 
 	) -> Self
 	{
@@ -86,11 +100,28 @@ impl<T> JoinHandle<T>
 	}
 
 
-	/// Make a wrapper around [`futures_util::future::RemoteHandle`].
+	/// Make a wrapper around [`futures_util::future::RemoteHandle`]. The future needs to be
+	/// wrapped with [`AbortHandle::wrap`] before calling `remote_handle` on it, so the task can be aborted.
 	//
-	pub fn remote_handle( handle: RemoteHandle<T> ) -> Self
+	pub fn remote_handle( handle: RemoteHandle<Result<T, Aborted>>, a_handle: AbortHandle ) -> Self
 	{
-		let inner = InnerJh::RemoteHandle{ handle: Some(handle) };
+		let inner = InnerJh::RemoteHandle{ handle: Some(handle), a_handle };
+
+		Self{ inner }
+	}
+
+
+
+	/// Make a handle for a task which catches it's own panic, see [`CatchUnwind`](crate::CatchUnwind).
+	/// The handle of the task on the wrapped executor is detached, it's abort handle keeps the native id.
+	//
+	pub(crate) fn catch_unwind( handle: RemoteHandle<std::thread::Result<T>>, task: JoinHandle<()> ) -> Self
+	{
+		let a_handle = task.abort_handle();
+
+		task.detach();
+
+		let inner = InnerJh::CatchUnwind{ handle: Some(handle), a_handle };
 
 		Self{ inner }
 	}
@@ -118,7 +149,8 @@ enum InnerJh<T>
 	//
 	AsyncGlobal
 	{
-		task: Option< AsyncGlobalTask<T> > ,
+		task    : Option< AsyncGlobalTask<Result<std::thread::Result<T>, Aborted>> > ,
+		a_handle: AbortHandle                                                        ,
 	},
 
 	/// Wrapper around AsyncStd JoinHandle.
@@ -127,16 +159,25 @@ enum InnerJh<T>
 	//
 	AsyncStd
 	{
-		handle  : AsyncStdJoinHandle<Result<T, Aborted>> ,
-		a_handle: AbortHandle                            ,
-		detached: bool                                   ,
+		handle  : AsyncStdJoinHandle<Result<std::thread::Result<T>, Aborted>> ,
+		a_handle: AbortHandle                                                 ,
+		detached: bool                                                        ,
 	},
 
 	/// Wrapper around futures RemoteHandle.
 	//
 	RemoteHandle
 	{
-		handle: Option<RemoteHandle<T>>,
+		handle  : Option< RemoteHandle<Result<T, Aborted>> > ,
+		a_handle: AbortHandle                                ,
+	},
+
+	/// Wrapper around futures RemoteHandle for a task which caught it's own panic.
+	//
+	CatchUnwind
+	{
+		handle  : Option< RemoteHandle<std::thread::Result<T>> > ,
+		a_handle: AbortHandle                                    ,
 	},
 }
 
@@ -163,7 +204,7 @@ impl<T> JoinHandle<T>
 				*detached = true;
 			}
 
-			#[ cfg( feature = "async_global" ) ] InnerJh::AsyncGlobal{ task } =>
+			#[ cfg( feature = "async_global" ) ] InnerJh::AsyncGlobal{ task, .. } =>
 			{
 				let task = task.take();
 				task.unwrap().detach();
@@ -174,23 +215,123 @@ impl<T> JoinHandle<T>
 				*detached = true;
 			}
 
-			InnerJh::RemoteHandle{ handle } =>
+			InnerJh::RemoteHandle{ handle, .. } =>
 			{
 				if let Some(rh) = handle.take() { rh.forget() };
 			}
+
+			InnerJh::CatchUnwind{ handle, .. } =>
+			{
+				if let Some(rh) = handle.take() { rh.forget() };
+			}
+		}
+	}
+
+
+
+	/// Cancel the task. Awaiting this handle with [`try_join`](JoinHandle::try_join) will return
+	/// [`JoinError::Cancelled`] unless the task had already completed.
+	///
+	/// This does not wait for the task to be dropped. Use [`is_finished`](JoinHandle::is_finished)
+	/// or await the handle to find out when it is.
+	//
+	pub fn abort( &self )
+	{
+		match &self.inner
+		{
+			#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct" )) ]
+			//
+			InnerJh::Tokio{ handle, .. } => handle.abort(),
+
+			#[ cfg( feature = "async_global" ) ] InnerJh::AsyncGlobal { a_handle, .. } => a_handle.abort(),
+			#[ cfg( feature = "async_std"    ) ] InnerJh::AsyncStd    { a_handle, .. } => a_handle.abort(),
+
+			InnerJh::RemoteHandle{ a_handle, .. } => a_handle.abort(),
+			InnerJh::CatchUnwind { a_handle, .. } => a_handle.abort(),
+		}
+	}
+
+
+
+	/// Get a cloneable [`AbortHandle`] that can cancel this task from elsewhere, without
+	/// having to own the `JoinHandle`.
+	//
+	pub fn abort_handle( &self ) -> AbortHandle
+	{
+		match &self.inner
+		{
+			#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct" )) ]
+			//
+			InnerJh::Tokio{ handle, .. } => AbortHandle::tokio( handle.abort_handle() ),
+
+			#[ cfg( feature = "async_global" ) ] InnerJh::AsyncGlobal { a_handle, .. } => a_handle.clone(),
+			#[ cfg( feature = "async_std"    ) ] InnerJh::AsyncStd    { a_handle, .. } => a_handle.clone(),
+
+			InnerJh::RemoteHandle{ a_handle, .. } => a_handle.clone(),
+			InnerJh::CatchUnwind { a_handle, .. } => a_handle.clone(),
+		}
+	}
+
+
+
+	/// The id of the task.
+	//
+	pub fn id( &self ) -> TaskId
+	{
+		match &self.inner
+		{
+			#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct" )) ]
+			//
+			InnerJh::Tokio{ handle, .. } => TaskId::tokio( handle.id() ),
+
+			#[ cfg( feature = "async_global" ) ] InnerJh::AsyncGlobal { a_handle, .. } => a_handle.id(),
+			#[ cfg( feature = "async_std"    ) ] InnerJh::AsyncStd    { a_handle, .. } => a_handle.id(),
+
+			InnerJh::RemoteHandle{ a_handle, .. } => a_handle.id(),
+			InnerJh::CatchUnwind { a_handle, .. } => a_handle.id(),
+		}
+	}
+
+
+
+	/// Whether the task has finished. This is the case when it ran to completion, when it panicked and
+	/// when it was aborted and subsequently dropped by the executor.
+	//
+	pub fn is_finished( &self ) -> bool
+	{
+		match &self.inner
+		{
+			#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct" )) ]
+			//
+			InnerJh::Tokio{ handle, .. } => handle.is_finished(),
+
+			#[ cfg( feature = "async_global" ) ] InnerJh::AsyncGlobal { a_handle, .. } => a_handle.is_finished(),
+			#[ cfg( feature = "async_std"    ) ] InnerJh::AsyncStd    { a_handle, .. } => a_handle.is_finished(),
+
+			InnerJh::RemoteHandle{ a_handle, .. } => a_handle.is_finished(),
+			InnerJh::CatchUnwind { a_handle, .. } => a_handle.is_finished(),
 		}
 	}
 }
 
 
 
-impl<T: 'static> Future for JoinHandle<T>
+impl<T: 'static> JoinHandle<T>
 {
-	type Output = T;
+	/// Await the output of the task without panicking if it did not complete. This resolves to
+	/// a [`JoinError`] if the task was canceled or if it panicked, so you can log and recover from
+	/// a crashed subtask.
+	//
+	pub fn try_join( self ) -> TryJoin<T>
+	{
+		TryJoin{ handle: self }
+	}
 
-	fn poll( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Self::Output>
+
+
+	pub(crate) fn poll_try_join( &mut self, cx: &mut Context<'_> ) -> Poll< Result<T, JoinError> >
 	{
-		match &mut self.get_mut().inner
+		match &mut self.inner
 		{
 			#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct" )) ]
 			//
@@ -198,11 +339,12 @@ impl<T: 'static> Future for JoinHandle<T>
 			{
 				match ready!( Pin::new( handle ).poll( cx ) )
 				{
-					Ok (t) => Poll::Ready( t ),
+					Ok (t) => Poll::Ready( Ok(t) ),
 
-					Err(e) =>
+					Err(e) => match e.try_into_panic()
 					{
-						panic!( "Task has been canceled or it has panicked. Are you dropping the executor to early? Error: {}", e );
+						Ok (payload) => Poll::Ready( Err( JoinError::Panicked(payload) ) ),
+						Err(_      ) => Poll::Ready( Err( JoinError::Cancelled         ) ),
 					}
 				}
 			}
@@ -210,27 +352,153 @@ impl<T: 'static> Future for JoinHandle<T>
 
 			#[ cfg( feature = "async_std" ) ] InnerJh::AsyncStd{ handle, .. } =>
 			{
-				match ready!( Pin::new( handle ).poll( cx ) )
+				Poll::Ready( caught( ready!( catch_poll( handle, cx ) ) ) )
+			}
+
+
+			#[ cfg( feature = "async_global" ) ] InnerJh::AsyncGlobal{ task, .. } =>
+			{
+				Poll::Ready( caught( ready!( catch_poll( task.as_mut().unwrap(), cx ) ) ) )
+			}
+
+
+			// RemoteHandle catches the panic and resumes it when polled, or panics with `Canceled`
+			// when the task was dropped by the executor.
+			//
+			InnerJh::RemoteHandle{ ref mut handle, .. } =>
+			{
+				let handle = handle.as_mut().expect( "no polling after detach" );
+
+				match ready!( catch_poll( handle, cx ) )
 				{
-					Ok (t) => Poll::Ready( t ),
-					Err(_) => unreachable!(),
+					Ok ( Ok(t)   ) => Poll::Ready( Ok(t)                       ) ,
+					Ok ( Err(_)  ) => Poll::Ready( Err( JoinError::Cancelled ) ) ,
+
+					Err( payload ) if payload.is::<futures_channel::oneshot::Canceled>() =>
+
+						Poll::Ready( Err( JoinError::Cancelled ) ),
+
+					Err( payload ) => Poll::Ready( Err( JoinError::Panicked(payload) ) ),
 				}
 			}
 
 
-			#[ cfg( feature = "async_global" ) ] InnerJh::AsyncGlobal{ task, .. } =>
+			// The task hands over the payload of it's panic. RemoteHandle only panics with `Canceled`
+			// when the task was dropped by the executor.
+			//
+			InnerJh::CatchUnwind{ ref mut handle, .. } =>
 			{
-				Pin::new( task.as_mut().unwrap() ).poll( cx )
+				let handle = handle.as_mut().expect( "no polling after detach" );
+
+				match ready!( catch_poll( handle, cx ) )
+				{
+					Ok ( Ok(t)        ) => Poll::Ready( Ok(t)                                ) ,
+					Ok ( Err(payload) ) => Poll::Ready( Err( JoinError::Panicked(payload) ) ) ,
+					Err( _            ) => Poll::Ready( Err( JoinError::Cancelled          ) ) ,
+				}
 			}
+		}
+	}
+}
+
+
+
+// The outcome of a task which caught it's own panic. The task panicking can't unwind out of the
+// native handle anymore, so when polling the handle panics, the executor dropped the task.
+//
+#[ cfg(any( feature = "async_std", feature = "async_global" )) ]
+//
+fn caught<T>( out: std::thread::Result< Result<std::thread::Result<T>, Aborted> > ) -> Result<T, JoinError>
+{
+	match out
+	{
+		Ok( Ok( Ok(t)       ) ) => Ok(t)                               ,
+		Ok( Ok( Err(payload)) ) => Err( JoinError::Panicked(payload) ) ,
+		Ok( Err(_)            ) => Err( JoinError::Cancelled         ) ,
+		Err(_)                  => Err( JoinError::Cancelled         ) ,
+	}
+}
+
+
+
+// Poll the future, catching a panic to return the payload.
+//
+fn catch_poll<F>( fut: &mut F, cx: &mut Context<'_> ) -> Poll< std::thread::Result<F::Output> >
+
+	where F: Future + Unpin,
+{
+	match catch_unwind( AssertUnwindSafe( || Pin::new( fut ).poll( cx ) ) )
+	{
+		Ok ( Poll::Pending  ) => Poll::Pending                 ,
+		Ok ( Poll::Ready(t) ) => Poll::Ready( Ok(t)        ) ,
+		Err( payload        ) => Poll::Ready( Err(payload) ) ,
+	}
+}
+
+
+
+impl<T: 'static> Future for JoinHandle<T>
+{
+	type Output = T;
+
+	fn poll( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Self::Output>
+	{
+		match ready!( self.get_mut().poll_try_join( cx ) )
+		{
+			Ok(t) => Poll::Ready( t ),
 
+			Err( JoinError::Panicked(payload) ) => resume_unwind( payload ),
 
-			InnerJh::RemoteHandle{ ref mut handle } => Pin::new( handle ).as_pin_mut().expect( "no polling after detach" ).poll( cx ),
+			Err( JoinError::Cancelled ) =>
+			{
+				panic!( "Task has been canceled. Are you dropping the executor to early?" );
+			}
 		}
 	}
 }
 
 
 
+/// Future returned by [`JoinHandle::try_join`]. Resolves to the output of the task or to
+/// a [`JoinError`] if the task was canceled or panicked.
+///
+/// Dropping this cancels the task, just like dropping the [`JoinHandle`].
+//
+#[ derive( Debug ) ]
+//
+#[ must_use = "TryJoin will cancel your future when dropped unless you await it." ]
+//
+pub struct TryJoin<T>
+{
+	handle: JoinHandle<T>,
+}
+
+
+
+impl<T> TryJoin<T>
+{
+	/// Cancel the task. See [`JoinHandle::abort`].
+	//
+	pub fn abort( &self )
+	{
+		self.handle.abort()
+	}
+}
+
+
+
+impl<T: 'static> Future for TryJoin<T>
+{
+	type Output = Result<T, JoinError>;
+
+	fn poll( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Self::Output>
+	{
+		self.get_mut().handle.poll_try_join( cx )
+	}
+}
+
+
+
 impl<T> Drop for JoinHandle<T>
 {
 	// see reasoning about Relaxed atomic in detach().
@@ -257,6 +525,7 @@ impl<T> Drop for JoinHandle<T>
 
 
 			InnerJh::RemoteHandle{ .. } => {},
+			InnerJh::CatchUnwind { .. } => {},
 		};
 	}
 }
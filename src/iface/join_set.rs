@@ -0,0 +1,173 @@
+use
+{
+	crate        :: { SpawnHandle, SpawnHandleExt, LocalSpawnHandle, LocalSpawnHandleExt } ,
+	crate        :: { JoinHandle, JoinError, AbortHandle, TryJoin                         } ,
+	std          :: { fmt, future::Future, task::{ Context, Poll }                        } ,
+	futures_task :: { SpawnError                                                          } ,
+	futures_util :: { stream::{ FuturesUnordered, StreamExt }                             } ,
+};
+
+
+/// A collection of spawned tasks which can be awaited in the order they complete. This works with any
+/// executor that implements [`SpawnHandle`] or [`LocalSpawnHandle`], as the executor is passed in when spawning.
+///
+/// Dropping the `JoinSet` aborts all tasks that are still in it.
+///
+/// ```
+/// use async_executors::{ JoinSet, SpawnHandle };
+///
+/// async fn sum_all( exec: impl SpawnHandle<u8> ) -> u8
+/// {
+///    let mut set = JoinSet::new();
+///
+///    for i in 0..5
+///    {
+///       set.spawn( &exec, async move { i * 2 } ).expect( "spawn" );
+///    }
+///
+///    let mut sum = 0;
+///
+///    while let Some(out) = set.join_next().await
+///    {
+///       sum += out.expect( "task completed" );
+///    }
+///
+///    sum
+/// }
+/// ```
+//
+#[ must_use = "JoinSet will cancel your tasks when dropped." ]
+//
+pub struct JoinSet<T>
+{
+	tasks: FuturesUnordered< TryJoin<T> >,
+}
+
+
+
+impl<T: 'static> JoinSet<T>
+{
+	/// Create an empty `JoinSet`.
+	//
+	pub fn new() -> Self
+	{
+		Self { tasks: FuturesUnordered::new() }
+	}
+
+
+	/// The number of tasks in the set. Tasks are removed when their output is returned from
+	/// [`join_next`](JoinSet::join_next).
+	//
+	pub fn len( &self ) -> usize
+	{
+		self.tasks.len()
+	}
+
+
+	/// Whether the set contains no tasks.
+	//
+	pub fn is_empty( &self ) -> bool
+	{
+		self.tasks.is_empty()
+	}
+
+
+	/// Spawn a future on `exec` and add it to the set. The returned [`AbortHandle`] can be used to cancel the
+	/// task without removing it from the set.
+	//
+	pub fn spawn<F>( &mut self, exec: &(impl SpawnHandle<T> + ?Sized), future: F ) -> Result<AbortHandle, SpawnError>
+
+		where F: Future<Output = T> + Send + 'static ,
+		      T: Send                                ,
+	{
+		let handle = exec.spawn_handle( future )?;
+
+		Ok( self.insert( handle ) )
+	}
+
+
+	/// Spawn a `!Send` future on `exec` and add it to the set. The returned [`AbortHandle`] can be used
+	/// to cancel the task without removing it from the set.
+	//
+	pub fn spawn_local<F>( &mut self, exec: &(impl LocalSpawnHandle<T> + ?Sized), future: F ) -> Result<AbortHandle, SpawnError>
+
+		where F: Future<Output = T> + 'static ,
+	{
+		let handle = exec.spawn_handle_local( future )?;
+
+		Ok( self.insert( handle ) )
+	}
+
+
+	/// Add a task that was already spawned to the set.
+	//
+	pub fn insert( &mut self, handle: JoinHandle<T> ) -> AbortHandle
+	{
+		let a_handle = handle.abort_handle();
+
+		self.tasks.push( handle.try_join() );
+
+		a_handle
+	}
+
+
+	/// Wait for one of the tasks in the set to complete and return it's output. Returns `None` if
+	/// the set is empty.
+	///
+	/// Tasks that were aborted or that panicked return a [`JoinError`].
+	//
+	pub async fn join_next( &mut self ) -> Option< Result<T, JoinError> >
+	{
+		self.tasks.next().await
+	}
+
+
+	/// Poll for the next task to complete. Returns `Poll::Ready(None)` if the set is empty.
+	//
+	pub fn poll_join_next( &mut self, cx: &mut Context<'_> ) -> Poll< Option< Result<T, JoinError> > >
+	{
+		self.tasks.poll_next_unpin( cx )
+	}
+
+
+	/// Abort all tasks in the set. The tasks remain in the set and will be returned with
+	/// [`JoinError::Cancelled`] from [`join_next`](JoinSet::join_next), unless they completed before
+	/// being aborted.
+	//
+	pub fn abort_all( &mut self )
+	{
+		self.tasks.iter().for_each( TryJoin::abort );
+	}
+
+
+	/// Abort all tasks and wait for them to finish. The set will be empty when this returns.
+	//
+	pub async fn shutdown( &mut self )
+	{
+		self.abort_all();
+
+		while self.join_next().await.is_some() {}
+	}
+}
+
+
+
+impl<T: 'static> Default for JoinSet<T>
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+
+
+impl<T> fmt::Debug for JoinSet<T>
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		f.debug_struct( "JoinSet" )
+			.field( "len", &self.tasks.len() )
+			.finish()
+	}
+}
@@ -4,7 +4,7 @@ use
 {
 	futures_task :: { SpawnError, LocalFutureObj                                          } ,
 	futures_util :: { task::{ LocalSpawnExt }, future::{ FutureExt, abortable }           } ,
-	crate        :: { JoinHandle                                                          } ,
+	crate        :: { JoinHandle, Named                                                   } ,
 	std          :: { pin::Pin, future::Future, sync::{ Arc, atomic::AtomicBool }, rc::Rc } ,
 	blanket      :: { blanket                                                             } ,
 };
@@ -20,6 +20,14 @@ pub trait LocalSpawnHandle<Out: 'static>
 	/// Spawn a future and return a [`JoinHandle`] that can be awaited for the output of the future.
 	//
 	fn spawn_handle_local_obj( &self, future: LocalFutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>;
+
+	/// Spawn a future with a name and return a [`JoinHandle`] that can be awaited for the output of the future.
+	/// See [`SpawnHandle::spawn_handle_obj_named`](crate::SpawnHandle::spawn_handle_obj_named).
+	//
+	fn spawn_handle_local_obj_named( &self, future: LocalFutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.spawn_handle_local_obj( LocalFutureObj::new(Named::new( future, name ).boxed_local()) )
+	}
 }
 
 
@@ -31,6 +39,10 @@ pub trait LocalSpawnHandleExt<Out: 'static> : LocalSpawnHandle<Out>
 	/// futures library.
 	//
 	fn spawn_handle_local( &self, future: impl Future<Output = Out> + 'static ) -> Result<JoinHandle<Out>, SpawnError>;
+
+	/// Spawn a `!Send` future with a name. See [`SpawnHandle::spawn_handle_obj_named`](crate::SpawnHandle::spawn_handle_obj_named).
+	//
+	fn spawn_local_named( &self, name: &str, future: impl Future<Output = Out> + 'static ) -> Result<JoinHandle<Out>, SpawnError>;
 }
 
 
@@ -39,8 +51,17 @@ impl<T, Out> LocalSpawnHandleExt<Out> for T
 	where T  : LocalSpawnHandle<Out> + ?Sized ,
 	      Out: 'static                        ,
 {
+	#[ track_caller ]
+	//
 	fn spawn_handle_local( &self, future: impl Future<Output = Out> + 'static ) -> Result<JoinHandle<Out>, SpawnError>
 	{
 		self.spawn_handle_local_obj( LocalFutureObj::new(future.boxed_local()) )
 	}
+
+	#[ track_caller ]
+	//
+	fn spawn_local_named( &self, name: &str, future: impl Future<Output = Out> + 'static ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.spawn_handle_local_obj_named( LocalFutureObj::new(future.boxed_local()), name )
+	}
 }
@@ -1,17 +1,37 @@
+pub(crate) mod abort_handle       ;
+pub(crate) mod block_on           ;
 pub(crate) mod blocking_handle    ;
+pub(crate) mod join_error         ;
 pub(crate) mod join_handle        ;
+pub(crate) mod join_set           ;
 pub(crate) mod local_spawn_handle ;
+pub(crate) mod shutdown           ;
 pub(crate) mod spawn_blocking     ;
 pub(crate) mod spawn_handle       ;
+pub(crate) mod task_id            ;
+pub(crate) mod task_name          ;
+pub(crate) mod task_tracker       ;
 pub(crate) mod timer              ;
+pub(crate) mod timer_stream       ;
+pub(crate) mod unboxed_timer      ;
 pub(crate) mod yield_now          ;
 
+pub use abort_handle       ::*;
+pub use block_on           ::*;
 pub use blocking_handle    ::*;
+pub use join_error         ::*;
 pub use join_handle        ::*;
+pub use join_set           ::*;
 pub use local_spawn_handle ::*;
+pub use shutdown           ::*;
 pub use spawn_blocking     ::*;
 pub use spawn_handle       ::*;
+pub use task_id            ::*;
+pub use task_name          ::*;
+pub(crate) use task_tracker ::*;
 pub use timer              ::*;
+pub use timer_stream       ::*;
+pub use unboxed_timer      ::*;
 pub use yield_now          ::*;
 
 
@@ -0,0 +1,76 @@
+use
+{
+	std          :: { time::Duration    } ,
+	futures_core :: { future::BoxFuture } ,
+	blanket      :: { blanket           } ,
+};
+
+
+/// Graceful shutdown of the tasks spawned on an executor. This is implemented by [`Tracked`](crate::Tracked),
+/// which wraps any executor that has a [`Timer`](crate::Timer). Executors don't keep track of their tasks
+/// unless they are wrapped, so spawning stays cheap when you don't need this.
+///
+/// Shutting down:
+///
+/// 1. stops accepting new tasks. Spawning on the wrapper, or any of it's clones, will return [`SpawnError::shutdown`](futures_task::SpawnError::shutdown),
+/// 2. waits up to `timeout` for the tasks that are still running to finish,
+/// 3. aborts the tasks that are still running after the timeout.
+///
+/// Only tasks spawned through the wrapper are taken into account. Blocking tasks from
+/// [`SpawnBlocking`](crate::SpawnBlocking) can't be aborted and are not tracked.
+///
+/// The timeout uses the [`Timer`](crate::Timer) of the executor, which is created when `shutdown` is called. With
+/// the `tokio_timer` feature, that has to happen within the tokio runtime.
+///
+/// The single threaded executors only run tasks while they are being driven by `block_on`, so await the
+/// returned future from within [`block_on`](crate::BlockOn::block_on), otherwise the remaining tasks can
+/// only be aborted.
+///
+/// ```
+/// # #[ cfg(all( feature = "tokio_ct", any( feature = "timer", feature = "tokio_timer" ) )) ]
+/// # {
+/// use async_executors::{ BlockOn, Shutdown, SpawnHandle, SpawnHandleExt, TokioCt, Tracked };
+/// use std::time::Duration;
+///
+/// let exec = Tracked::new( TokioCt::new().expect( "create tokio current thread" ) );
+///
+/// let report = exec.block_on( async
+/// {
+///    exec.spawn_handle( async {} ).expect( "spawn" ).detach();
+///
+///    exec.shutdown( Duration::from_secs(1) ).await
+/// });
+///
+/// assert_eq!( report.drained, 1 );
+/// assert_eq!( report.aborted, 0 );
+/// assert!( exec.spawn_handle( async {} ).is_err() );
+/// # }
+/// ```
+//
+#[ blanket( derive( Ref, Mut, Rc, Arc, Box ) ) ]
+//
+pub trait Shutdown
+{
+	/// Stop accepting new tasks, wait up to `timeout` for the running tasks to finish and abort the rest.
+	//
+	#[ must_use = "shutdown() returns a future, which does nothing unless awaited" ]
+	//
+	fn shutdown( &self, timeout: Duration ) -> BoxFuture<'static, ShutdownReport>;
+}
+
+
+
+/// What happened to the tasks that were still running when [`Shutdown::shutdown`] was called.
+//
+#[ derive( Debug, Copy, Clone, Default, PartialEq, Eq ) ]
+//
+pub struct ShutdownReport
+{
+	/// The number of tasks that finished before the timeout.
+	//
+	pub drained: usize,
+
+	/// The number of tasks that were still running after the timeout and have been aborted.
+	//
+	pub aborted: usize,
+}
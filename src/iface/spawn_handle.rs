@@ -4,7 +4,7 @@ use
 {
 	futures_util :: { future::{ FutureExt, abortable }, task::SpawnExt                    } ,
 	futures_task :: { SpawnError, FutureObj                                               } ,
-	crate        :: { JoinHandle                                                          } ,
+	crate        :: { JoinHandle, Named                                                   } ,
 	std          :: { pin::Pin, future::Future, sync::{ Arc, atomic::AtomicBool }, rc::Rc } ,
 	blanket      :: { blanket                                                             } ,
 };
@@ -59,6 +59,17 @@ pub trait SpawnHandle<Out: 'static + Send>
 	/// Spawn a future and return a [`JoinHandle`] that can be awaited for the output of the future.
 	//
 	fn spawn_handle_obj( &self, future: FutureObj<'static, Out> ) -> Result<JoinHandle<Out>, SpawnError>;
+
+	/// Spawn a future with a name and return a [`JoinHandle`] that can be awaited for the output of the future.
+	/// The name can be retrieved from within the task with [`task_name`](crate::task_name).
+	///
+	/// The default implementation only records the name for [`task_name`](crate::task_name). Executors that
+	/// support naming tasks natively override this to also pass on the name to the executor.
+	//
+	fn spawn_handle_obj_named( &self, future: FutureObj<'static, Out>, name: &str ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.spawn_handle_obj( FutureObj::new(Named::new( future, name ).boxed()) )
+	}
 }
 
 /// Convenience trait for passing in a generic future to [`SpawnHandle`]. Much akin to `Spawn` and `SpawnExt` in the
@@ -69,6 +80,11 @@ pub trait SpawnHandleExt<Out: 'static + Send> : SpawnHandle<Out>
 	/// Spawn a future and return a [JoinHandle] that can be awaited for the output of the future.
 	//
 	fn spawn_handle( &self, future: impl Future<Output = Out> + Send + 'static ) -> Result<JoinHandle<Out>, SpawnError>;
+
+	/// Spawn a future with a name and return a [JoinHandle] that can be awaited for the output of the future.
+	/// See [`SpawnHandle::spawn_handle_obj_named`].
+	//
+	fn spawn_named( &self, name: &str, future: impl Future<Output = Out> + Send + 'static ) -> Result<JoinHandle<Out>, SpawnError>;
 }
 
 
@@ -77,8 +93,17 @@ impl<T, Out> SpawnHandleExt<Out> for T
 	where T  : SpawnHandle<Out> + ?Sized ,
 	      Out: 'static + Send            ,
 {
+	#[ track_caller ]
+	//
 	fn spawn_handle( &self, future: impl Future<Output = Out> + Send + 'static ) -> Result<JoinHandle<Out>, SpawnError>
 	{
 		self.spawn_handle_obj( FutureObj::new(future.boxed()) )
 	}
+
+	#[ track_caller ]
+	//
+	fn spawn_named( &self, name: &str, future: impl Future<Output = Out> + Send + 'static ) -> Result<JoinHandle<Out>, SpawnError>
+	{
+		self.spawn_handle_obj_named( FutureObj::new(future.boxed()), name )
+	}
 }
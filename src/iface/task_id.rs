@@ -0,0 +1,131 @@
+use
+{
+	std :: { fmt, cell::Cell, num::NonZeroU64, sync::atomic::{ AtomicU64, Ordering } } ,
+};
+
+#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct" )) ]
+//
+use tokio::task::{ Id as TokioId };
+
+
+
+/// An identifier for a spawned task, unique among the tasks currently running in the process.
+/// Get it from [`JoinHandle::id`](crate::JoinHandle::id) or from within the task with [`task_id`].
+///
+/// On tokio this maps to [`tokio::task::Id`]. On other executors the id is generated by this crate when the
+/// task is spawned through [`SpawnHandle`](crate::SpawnHandle) or [`LocalSpawnHandle`](crate::LocalSpawnHandle).
+///
+/// Both kinds of ids count up independently, so the number alone is not unique. The [`Display`](fmt::Display)
+/// output prefixes tokio ids with `tokio-`, so ids from both render differently in logs.
+//
+#[ derive( Debug, Copy, Clone, PartialEq, Eq, Hash ) ]
+//
+pub struct TaskId( InnerId );
+
+
+
+#[ derive( Debug, Copy, Clone, PartialEq, Eq, Hash ) ]
+//
+enum InnerId
+{
+	#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct" )) ]
+	//
+	Tokio( TokioId ),
+
+	Crate( NonZeroU64 ),
+}
+
+
+
+// 0 is never handed out, so the ids fit in a NonZeroU64.
+//
+static NEXT_ID: AtomicU64 = AtomicU64::new( 1 );
+
+
+thread_local!
+{
+	// The id of the task we are currently polling, if it was generated by this crate.
+	//
+	static CURRENT: Cell< Option<TaskId> > = const { Cell::new( None ) };
+}
+
+
+
+impl TaskId
+{
+	/// Make a wrapper around [`tokio::task::Id`].
+	//
+	#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct" )) ]
+	//
+	pub fn tokio( id: TokioId ) -> Self
+	{
+		Self( InnerId::Tokio(id) )
+	}
+
+
+	/// Generate a new id for executors that have no native task ids.
+	//
+	pub(crate) fn next() -> Self
+	{
+		let id = NEXT_ID.fetch_add( 1, Ordering::Relaxed );
+
+		Self( InnerId::Crate( NonZeroU64::new(id).expect( "TaskId overflow" ) ) )
+	}
+
+
+	/// Run `f` with `self` set as the id of the current task. Used when polling a task.
+	//
+	pub(crate) fn enter<R>( self, f: impl FnOnce() -> R ) -> R
+	{
+		// Restores the previous value, even when `f` panics.
+		//
+		struct Reset( Option<TaskId> );
+
+		impl Drop for Reset
+		{
+			fn drop( &mut self )
+			{
+				CURRENT.with( |c| c.set( self.0 ) );
+			}
+		}
+
+		let _reset = Reset( CURRENT.with( |c| c.replace( Some(self) ) ) );
+
+		f()
+	}
+}
+
+
+
+impl fmt::Display for TaskId
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		match self.0
+		{
+			#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct" )) ]
+			//
+			InnerId::Tokio( id ) => write!( f, "tokio-{id}" ),
+
+			InnerId::Crate( id ) => write!( f, "{id}" ),
+		}
+	}
+}
+
+
+
+/// Returns the id of the task that is currently running, or `None` when called outside of a task.
+///
+/// For executors without native task ids, only tasks spawned through [`SpawnHandle`](crate::SpawnHandle)
+/// or [`LocalSpawnHandle`](crate::LocalSpawnHandle) have an id. Futures run by `block_on` are not tasks.
+//
+pub fn task_id() -> Option<TaskId>
+{
+	let current = CURRENT.with( Cell::get );
+
+	#[ cfg(any( feature = "tokio_tp", feature = "tokio_ct" )) ]
+	//
+	let current = current.or_else( || tokio::task::try_id().map( TaskId::tokio ) );
+
+	current
+}
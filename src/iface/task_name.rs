@@ -0,0 +1,81 @@
+use
+{
+	std         :: { cell::RefCell, future::Future, pin::Pin, sync::Arc, task::{ Poll, Context } } ,
+	pin_project :: { pin_project                                                                 } ,
+};
+
+
+
+thread_local!
+{
+	// The name of the task we are currently polling, if it has one.
+	//
+	static CURRENT: RefCell< Option<Arc<str>> > = const { RefCell::new( None ) };
+}
+
+
+
+/// Returns the name of the task that is currently running, or `None` when called outside of a task
+/// or when the task was spawned without a name.
+///
+/// Tasks get a name when spawned with [`SpawnHandleExt::spawn_named`](crate::SpawnHandleExt::spawn_named)
+/// or [`LocalSpawnHandleExt::spawn_local_named`](crate::LocalSpawnHandleExt::spawn_local_named).
+//
+pub fn task_name() -> Option< Arc<str> >
+{
+	CURRENT.with( |c| c.borrow().clone() )
+}
+
+
+
+/// Sets the name of the task as the current task name while it is being polled.
+//
+#[ pin_project ]
+//
+#[ must_use = "futures do nothing unless polled" ]
+//
+#[ derive( Debug ) ]
+//
+pub(crate) struct Named<F>
+{
+	#[pin] future: F,
+
+	name: Arc<str>,
+}
+
+
+
+impl<F> Named<F>
+{
+	pub(crate) fn new( future: F, name: &str ) -> Self
+	{
+		Self{ future, name: name.into() }
+	}
+}
+
+
+
+impl<F: Future> Future for Named<F>
+{
+	type Output = F::Output;
+
+	fn poll( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Self::Output>
+	{
+		// Restores the previous name, even when the future panics.
+		//
+		struct Reset( Option<Arc<str>> );
+
+		impl Drop for Reset
+		{
+			fn drop( &mut self )
+			{
+				CURRENT.with( |c| *c.borrow_mut() = self.0.take() );
+			}
+		}
+
+		let this   = self.project();
+		let _reset = Reset( CURRENT.with( |c| c.borrow_mut().replace( this.name.clone() ) ) );
+
+		this.future.poll( cx )
+	}
+}
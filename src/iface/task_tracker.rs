@@ -0,0 +1,290 @@
+use
+{
+	crate        :: { AbortHandle, ShutdownReport                                                } ,
+	std          :: { future::Future, pin::Pin, task::{ Poll, Context, Waker }                   } ,
+	std          :: { sync::{ Arc, Mutex, MutexGuard, PoisonError }                              } ,
+	std          :: { sync::atomic::{ AtomicUsize, Ordering }                                    } ,
+	futures_core :: { future::BoxFuture                                                          } ,
+	futures_task :: { SpawnError                                                                 } ,
+	futures_util :: { future::{ select, FutureExt }                                              } ,
+	pin_project  :: { pin_project                                                                } ,
+};
+
+
+
+// The highest bit of `Shared::state` marks the tracker as closed, the other bits count the active tasks.
+//
+const CLOSED: usize = 1 << ( usize::BITS - 1 );
+
+// Don't bother pruning finished tasks from the abort handles until there are at least this many.
+//
+const MIN_PRUNE: usize = 64;
+
+
+
+/// Keeps track of the tasks spawned on an executor, so we can tell when they have all finished,
+/// abort the ones that are still running and refuse new tasks after shutdown.
+///
+/// Executors wrap each future with [`TaskTracker::track`] before spawning it, and register the
+/// [`AbortHandle`] of the task once it has been spawned.
+///
+/// Counting tasks is lock free. Abort handles are only pushed on a list, finished tasks are pruned
+/// from it once in a while rather than removed as soon as they end.
+//
+#[ derive( Debug, Clone, Default ) ]
+//
+pub(crate) struct TaskTracker
+{
+	shared: Arc<Shared>,
+}
+
+
+
+#[ derive( Debug, Default ) ]
+//
+struct Shared
+{
+	state  : AtomicUsize          ,
+	handles: Mutex<AbortHandles>  ,
+	idle   : Mutex< Vec<Waker> >  ,
+}
+
+
+
+#[ derive( Debug, Default ) ]
+//
+struct AbortHandles
+{
+	list    : Vec<AbortHandle> ,
+	prune_at: usize            ,
+}
+
+
+
+// Neither lock is held while running user code, so poisoning can be ignored.
+//
+fn lock<T>( mutex: &Mutex<T> ) -> MutexGuard<'_, T>
+{
+	mutex.lock().unwrap_or_else( PoisonError::into_inner )
+}
+
+
+
+impl TaskTracker
+{
+	/// Wrap a future so it is counted as active until it is dropped. Fails if the tracker has been closed.
+	//
+	pub(crate) fn track<F: Future>( &self, future: F ) -> Result< TrackedFut<F>, SpawnError >
+	{
+		let mut state = self.shared.state.load( Ordering::Acquire );
+
+		loop
+		{
+			if state & CLOSED != 0
+			{
+				return Err( SpawnError::shutdown() );
+			}
+
+			match self.shared.state.compare_exchange_weak( state, state + 1, Ordering::AcqRel, Ordering::Acquire )
+			{
+				Ok (_     ) => break          ,
+				Err(actual) => state = actual ,
+			}
+		}
+
+		Ok( TrackedFut{ future, _guard: Untrack{ tracker: self.clone() } } )
+	}
+
+
+	/// Register the abort handle of a task after it has been spawned. If the tracker has been closed in
+	/// the meantime, the task is aborted right away.
+	//
+	pub(crate) fn register( &self, handle: AbortHandle )
+	{
+		let mut handles = lock( &self.shared.handles );
+
+		// Check under the lock, so we can't race with `abort_remaining` taking the list.
+		//
+		if self.is_closed()
+		{
+			drop( handles );
+			handle.abort();
+			return;
+		}
+
+		handles.list.push( handle );
+
+		if handles.list.len() >= handles.prune_at.max( MIN_PRUNE )
+		{
+			handles.list.retain( |h| !h.is_finished() );
+			handles.prune_at = handles.list.len() * 2;
+		}
+	}
+
+
+	/// The number of tasks that have not finished yet.
+	//
+	pub(crate) fn len( &self ) -> usize
+	{
+		self.shared.state.load( Ordering::Acquire ) & !CLOSED
+	}
+
+
+	/// Whether the tracker has been closed.
+	//
+	pub(crate) fn is_closed( &self ) -> bool
+	{
+		self.shared.state.load( Ordering::Acquire ) & CLOSED != 0
+	}
+
+
+	// Refuse new tasks. Returns the number of active tasks.
+	//
+	fn close( &self ) -> usize
+	{
+		self.shared.state.fetch_or( CLOSED, Ordering::AcqRel ) & !CLOSED
+	}
+
+
+	/// Refuse new tasks and abort all active ones. Returns the number of tasks aborted.
+	//
+	pub(crate) fn cancel( &self ) -> usize
+	{
+		self.close();
+		self.abort_remaining()
+	}
+
+
+	// Only call this once closed, so no more handles get registered. Don't hold the lock while
+	// aborting, the tasks might get dropped right away.
+	//
+	fn abort_remaining( &self ) -> usize
+	{
+		let remaining = self.len();
+		let handles   = std::mem::take( &mut lock( &self.shared.handles ).list );
+
+		handles.iter().for_each( AbortHandle::abort );
+		remaining
+	}
+
+
+	/// A future that resolves when no tasks are active.
+	//
+	pub(crate) fn idle( &self ) -> Idle
+	{
+		Idle{ tracker: self.clone() }
+	}
+
+
+	/// Refuse new tasks, wait for the active ones to finish until `deadline` resolves and abort the remaining ones.
+	//
+	pub(crate) fn shutdown( &self, deadline: BoxFuture<'static, ()> ) -> BoxFuture<'static, ShutdownReport>
+	{
+		// Close right away, not when the future is first polled.
+		//
+		let active  = self.close();
+		let tracker = self.clone();
+
+		async move
+		{
+			select( tracker.idle(), deadline ).await;
+
+			let aborted = tracker.abort_remaining();
+
+			ShutdownReport{ drained: active.saturating_sub( aborted ), aborted }
+
+		}.boxed()
+	}
+}
+
+
+
+/// A future that is tracked by a [`TaskTracker`]. Created with [`TaskTracker::track`].
+//
+#[ pin_project ]
+//
+#[ must_use = "futures do nothing unless polled" ]
+//
+#[ derive( Debug ) ]
+//
+pub(crate) struct TrackedFut<F>
+{
+	#[pin] future: F,
+
+	// Removes the task from the tracker when the future is dropped, whether it completed or not.
+	//
+	_guard: Untrack,
+}
+
+
+
+impl<F: Future> Future for TrackedFut<F>
+{
+	type Output = F::Output;
+
+	fn poll( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Self::Output>
+	{
+		self.project().future.poll( cx )
+	}
+}
+
+
+
+#[ derive( Debug ) ]
+//
+struct Untrack
+{
+	tracker: TaskTracker,
+}
+
+
+impl Drop for Untrack
+{
+	fn drop( &mut self )
+	{
+		let previous = self.tracker.shared.state.fetch_sub( 1, Ordering::AcqRel );
+
+		if previous & !CLOSED == 1
+		{
+			let wakers = std::mem::take( &mut *lock( &self.tracker.shared.idle ) );
+
+			wakers.into_iter().for_each( Waker::wake );
+		}
+	}
+}
+
+
+
+/// Resolves when no tasks are active on the [`TaskTracker`].
+//
+#[ derive( Debug ) ]
+//
+pub(crate) struct Idle
+{
+	tracker: TaskTracker,
+}
+
+
+impl Future for Idle
+{
+	type Output = ();
+
+	fn poll( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<()>
+	{
+		// Check the count under the lock, so the last task can't take the wakers in between.
+		//
+		let mut idle = lock( &self.tracker.shared.idle );
+
+		if self.tracker.len() == 0
+		{
+			return Poll::Ready(());
+		}
+
+		if !idle.iter().any( |w| w.will_wake( cx.waker() ) )
+		{
+			idle.push( cx.waker().clone() );
+		}
+
+		Poll::Pending
+	}
+}
@@ -1,7 +1,8 @@
 use
 {
-	std          :: { time::Duration, future::Future, task::{ Poll, Context }, pin::Pin } ,
-	futures_core :: { future::BoxFuture                                                 } ,
+	std          :: { time::{ Duration, Instant }, future::Future, task::{ Poll, Context }, pin::Pin } ,
+	futures_core :: { future::BoxFuture, Stream                                                      } ,
+	super        :: { StreamTimeout, Throttle, Debounce, Sample                                      } ,
 
 	pin_project::pin_project,
 };
@@ -10,10 +11,11 @@ use
 ///
 //  Implementation:
 //  - for tokio: use tokio when tokio_time feature is enabled, futures-timer otherwise.
-//  - for async-global-executor: use futures-timer.
-//  - for glommio: has own timer that can't be turned off. But we don't use it because
-//    it's not Send.
+//  - for async-global-executor: use async-io, futures-timer on Wasm.
+//  - for glommio: has own timer that can't be turned off. It's not Send, so it runs in a task
+//    that signals the sleep future over a channel.
 //  - for bindgen: use futures-timer
+//  - for anything else: wrap it in TimerOnly, which uses futures-timer.
 //  - for async-std: has a timer that cannot be turned off. Isn't Send on Wasm.
 //
 //  The trait needs to be available inconditionally, as a library must be able
@@ -28,6 +30,43 @@ pub trait Timer
 	#[ must_use = "sleep() returns a future, which does nothing unless awaited" ]
 	//
 	fn sleep( &self, dur: Duration ) -> BoxFuture<'static, ()>;
+
+
+	/// Future that resolves at `deadline`. Unlike chaining relative sleeps, this doesn't drift, so one deadline
+	/// can be shared by several operations.
+	///
+	/// The default sleeps for the time left until `deadline` according to [`current_time`](Timer::current_time).
+	/// Executors override it to use the deadline of the underlying timer where there is one.
+	//
+	#[ must_use = "sleep_until() returns a future, which does nothing unless awaited" ]
+	//
+	fn sleep_until( &self, deadline: Instant ) -> BoxFuture<'static, ()>
+	{
+		let dur = self.current_time().map_or( Duration::ZERO, |now| deadline.saturating_duration_since( now ) );
+
+		self.sleep( dur )
+	}
+
+
+	/// The current time as seen by this timer, which [`Interval`] uses to catch up with missed ticks.
+	///
+	/// The default is [`Instant::now`], or `None` on Wasm where std has no clock. Timers that don't follow
+	/// the system clock, like [`ManualClock`](crate::ManualClock), override it.
+	//
+	fn current_time( &self ) -> Option<Instant>
+	{
+		now()
+	}
+}
+
+
+
+// There is no clock in std on Wasm.
+//
+pub(crate) fn now() -> Option<Instant>
+{
+	#[ cfg( target_os = "unknown" ) ] { None }
+	#[ cfg(not( target_os = "unknown" )) ] { Some( Instant::now() ) }
 }
 
 
@@ -39,7 +78,9 @@ pub trait Timer
 
 
 
-/// An extension trait on [`Timer`] for timeouts and clock delays.
+/// An extension trait on [`Timer`] for timeouts, clock delays and timing of streams.
+///
+/// Everything here is built on [`Timer::sleep`], so it behaves the same on every executor.
 //
 pub trait TimerExt: Timer
 {
@@ -62,6 +103,91 @@ pub trait TimerExt: Timer
 
 		Timeout { future, sleep_future }
 	}
+
+
+	/// Wrap a [`Future`] with a timeout at a fixed `deadline`. See [`timeout`](TimerExt::timeout)
+	/// and [`Timer::sleep_until`].
+	//
+	#[ must_use = "timeout_at() returns a future, which does nothing unless awaited." ]
+	//
+	fn timeout_at<F: Future>( &self, deadline: Instant, future: F ) -> Timeout<F>
+	{
+		let sleep_future = self.sleep_until( deadline );
+
+		Timeout { future, sleep_future }
+	}
+
+
+	/// A [`Stream`] that yields a tick every `period`. The first tick comes after one period. What happens
+	/// when the consumer falls behind is configured with [`Interval::missed_ticks`].
+	///
+	/// It holds a clone of the timer to create new sleeps.
+	///
+	/// # Panics
+	///
+	/// When `period` is zero.
+	//
+	#[ must_use = "interval() returns a stream, which does nothing unless polled." ]
+	//
+	fn interval( &self, period: Duration ) -> Interval<Self>
+
+		where Self: Clone + Sized
+	{
+		Interval::new( self.clone(), period )
+	}
+
+
+	/// Yield `Err(TimeoutError)` when `stream` doesn't produce an item within `duration`. The stream
+	/// keeps going after an error, see [`StreamTimeout`].
+	//
+	#[ must_use = "stream_timeout() returns a stream, which does nothing unless polled." ]
+	//
+	fn stream_timeout<S: Stream>( &self, duration: Duration, stream: S ) -> StreamTimeout<S, Self>
+
+		where Self: Clone + Sized
+	{
+		StreamTimeout::new( stream, self.clone(), duration )
+	}
+
+
+	/// Yield the items of `stream` with at least `period` between them, see [`Throttle`].
+	//
+	#[ must_use = "throttle() returns a stream, which does nothing unless polled." ]
+	//
+	fn throttle<S: Stream>( &self, period: Duration, stream: S ) -> Throttle<S, Self>
+
+		where Self: Clone + Sized
+	{
+		Throttle::new( stream, self.clone(), period )
+	}
+
+
+	/// Only yield an item of `stream` when no new item follows within `duration`, see [`Debounce`].
+	//
+	#[ must_use = "debounce() returns a stream, which does nothing unless polled." ]
+	//
+	fn debounce<S: Stream>( &self, duration: Duration, stream: S ) -> Debounce<S, Self>
+
+		where Self: Clone + Sized
+	{
+		Debounce::new( stream, self.clone(), duration )
+	}
+
+
+	/// Yield the latest item of `stream` every `period`, see [`Sample`].
+	///
+	/// # Panics
+	///
+	/// When `period` is zero.
+	//
+	#[ must_use = "sample() returns a stream, which does nothing unless polled." ]
+	//
+	fn sample<S: Stream>( &self, period: Duration, stream: S ) -> Sample<S, Self>
+
+		where Self: Clone + Sized
+	{
+		Sample::new( stream, self.interval( period ) )
+	}
 }
 
 
@@ -71,8 +197,8 @@ impl<T: Timer> TimerExt for T {}
 /// An error value given when a function times out.
 ///
 /// This value is generated when the timeout from
-/// [`TimerExt::timeout`] expires before the provided future
-/// is ready.
+/// [`TimerExt::timeout`] or [`TimerExt::timeout_at`] expires
+/// before the provided future is ready.
 //
 #[ derive( Copy, Clone, Debug, Eq, PartialEq ) ]
 //
@@ -102,26 +228,30 @@ impl From<TimeoutError> for std::io::Error
 }
 
 
-/// A timeout returned by [`TimerExt::timeout`].
+/// A timeout returned by [`TimerExt::timeout`] and [`TimerExt::timeout_at`].
+///
+/// `S` is the sleep future, which is only a concrete type when created through
+/// [`UnboxedTimer`](crate::UnboxedTimer).
 //
 #[pin_project]
 //
-pub struct Timeout<T>
+pub struct Timeout<T, S = BoxFuture<'static, ()>>
 {
 	/// The future we want to execute.
 	//
-	#[pin] future: T,
+	#[pin] pub(crate) future: T,
 
 	/// The future implementing the timeout.
 	//
-	sleep_future: BoxFuture<'static, ()>,
+	#[pin] pub(crate) sleep_future: S,
 }
 
 
 
-impl<T> Future for Timeout<T>
+impl<T, S> Future for Timeout<T, S>
 
 	where T: Future,
+	      S: Future<Output = ()>,
 
 {
 	type Output = Result< T::Output, TimeoutError >;
@@ -138,7 +268,7 @@ impl<T> Future for Timeout<T>
 		}
 
 
-		match this.sleep_future.as_mut().poll(cx)
+		match this.sleep_future.poll(cx)
 		{
 			Poll::Pending   => Poll::Pending                    ,
 			Poll::Ready(()) => Poll::Ready( Err(TimeoutError) ) ,
@@ -147,7 +277,7 @@ impl<T> Future for Timeout<T>
 }
 
 
-impl<T> std::fmt::Debug for Timeout<T>
+impl<T, S> std::fmt::Debug for Timeout<T, S>
 {
 	fn fmt( &self, f: &mut std::fmt::Formatter<'_> ) -> std::fmt::Result
 	{
@@ -155,3 +285,149 @@ impl<T> std::fmt::Debug for Timeout<T>
 	}
 }
 
+
+
+/// What an [`Interval`] does when ticks are missed because the consumer did not poll it in time,
+/// or the sleep woke up late.
+//
+#[ derive( Copy, Clone, Debug, Default, Eq, PartialEq ) ]
+//
+pub enum MissedTicks
+{
+	/// Yield all the missed ticks right away, then continue on the original schedule. This keeps
+	/// the number of ticks in line with the time that passed.
+	//
+	#[ default ]
+	//
+	Burst,
+
+	/// Yield one tick and schedule the next one a full period later, so the schedule shifts by the delay.
+	//
+	Delay,
+
+	/// Yield one tick and drop the other missed ones, continuing on the original schedule.
+	//
+	Skip,
+}
+
+
+
+/// A stream of ticks returned by [`TimerExt::interval`]. It never ends.
+///
+/// Missed ticks are detected with [`Timer::current_time`]. When the timer has no clock, as on Wasm,
+/// every tick is scheduled a period after the previous one, like [`MissedTicks::Delay`].
+//
+pub struct Interval<T>
+{
+	timer   : T                      ,
+	period  : Duration               ,
+	missed  : MissedTicks            ,
+	deadline: Option<Instant>        ,
+	sleep   : BoxFuture<'static, ()> ,
+}
+
+
+// The timer is never pinned.
+//
+impl<T> Unpin for Interval<T> {}
+
+
+
+impl<T: Timer> Interval<T>
+{
+	fn new( timer: T, period: Duration ) -> Self
+	{
+		assert!( !period.is_zero(), "Interval: the period can not be zero." );
+
+		let deadline = timer.current_time().map( |now| now + period );
+		let sleep    = timer.sleep( period );
+
+		Self{ timer, period, missed: MissedTicks::default(), deadline, sleep }
+	}
+
+
+	/// Set what happens when ticks are missed. The default is [`MissedTicks::Burst`].
+	//
+	pub fn missed_ticks( mut self, missed: MissedTicks ) -> Self
+	{
+		self.missed = missed;
+		self
+	}
+
+
+	/// The period between ticks.
+	//
+	pub fn period( &self ) -> Duration
+	{
+		self.period
+	}
+
+
+	// The deadline of the tick after the one that just fired.
+	//
+	fn next_deadline( &self, deadline: Instant, now: Instant ) -> Instant
+	{
+		match self.missed
+		{
+			MissedTicks::Burst => deadline + self.period,
+			MissedTicks::Delay => now      + self.period,
+
+			MissedTicks::Skip =>
+			{
+				let missed = now.saturating_duration_since( deadline ).as_nanos() / self.period.as_nanos();
+				let missed = u32::try_from( missed ).unwrap_or( u32::MAX );
+
+				deadline + self.period * missed.saturating_add( 1 )
+			}
+		}
+	}
+}
+
+
+
+impl<T: Timer> Stream for Interval<T>
+{
+	type Item = ();
+
+	fn poll_next( mut self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Option<()>>
+	{
+		let this = &mut *self;
+
+		if this.sleep.as_mut().poll( cx ).is_pending()
+		{
+			return Poll::Pending;
+		}
+
+		let next = match ( this.deadline, this.timer.current_time() )
+		{
+			( Some(deadline), Some(now) ) =>
+			{
+				let next = this.next_deadline( deadline, now );
+
+				this.deadline = Some( next );
+				next.saturating_duration_since( now )
+			}
+
+			_ => this.period,
+		};
+
+		this.sleep = this.timer.sleep( next );
+
+		Poll::Ready( Some(()) )
+	}
+}
+
+
+
+impl<T> std::fmt::Debug for Interval<T>
+{
+	fn fmt( &self, f: &mut std::fmt::Formatter<'_> ) -> std::fmt::Result
+	{
+		f.debug_struct( "Interval" )
+
+			.field( "period"  , &self.period   )
+			.field( "missed"  , &self.missed   )
+			.field( "deadline", &self.deadline )
+			.finish()
+	}
+}
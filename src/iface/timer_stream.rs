@@ -0,0 +1,320 @@
+use
+{
+	crate        :: { Timer, Interval, TimeoutError, MissedTicks             } ,
+	std          :: { time::Duration, task::{ Poll, Context }, pin::Pin, fmt } ,
+	futures_core :: { future::BoxFuture, Stream, ready                       } ,
+
+	pin_project::pin_project,
+};
+
+
+// The most items debounce and sample take from the stream per poll. When the stream is always ready, they
+// wake the task and return Pending after this, so it doesn't keep the executor busy.
+//
+const BUDGET: usize = 32;
+
+
+/// A stream returned by [`TimerExt::stream_timeout`](crate::TimerExt::stream_timeout).
+///
+/// Yields `Err(TimeoutError)` every time `duration` passes without an item. The timer restarts after
+/// every item and every error. It ends when the stream ends.
+//
+#[ pin_project ]
+//
+pub struct StreamTimeout<S, T>
+{
+	#[pin] stream  : S                      ,
+	       timer   : T                      ,
+	       duration: Duration               ,
+	       sleep   : BoxFuture<'static, ()> ,
+	       done    : bool                   ,
+}
+
+
+impl<S, T: Timer> StreamTimeout<S, T>
+{
+	pub(crate) fn new( stream: S, timer: T, duration: Duration ) -> Self
+	{
+		let sleep = timer.sleep( duration );
+
+		Self{ stream, timer, duration, sleep, done: false }
+	}
+}
+
+
+impl<S: Stream, T: Timer> Stream for StreamTimeout<S, T>
+{
+	type Item = Result<S::Item, TimeoutError>;
+
+	fn poll_next( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Option<Self::Item>>
+	{
+		let this = self.project();
+
+		if *this.done
+		{
+			return Poll::Ready( None );
+		}
+
+		if let Poll::Ready( item ) = this.stream.poll_next( cx )
+		{
+			*this.done  = item.is_none();
+			*this.sleep = this.timer.sleep( *this.duration );
+
+			return Poll::Ready( item.map( Ok ) );
+		}
+
+		ready!( this.sleep.as_mut().poll( cx ) );
+
+		*this.sleep = this.timer.sleep( *this.duration );
+
+		Poll::Ready( Some( Err(TimeoutError) ) )
+	}
+}
+
+
+
+/// A stream returned by [`TimerExt::throttle`](crate::TimerExt::throttle).
+///
+/// Yields the items of the stream with at least `period` between them. No items are dropped, they
+/// wait in the stream until it's their turn. The first item comes through right away.
+//
+#[ pin_project ]
+//
+pub struct Throttle<S, T>
+{
+	#[pin] stream: S                                ,
+	       timer : T                                ,
+	       period: Duration                         ,
+	       sleep : Option< BoxFuture<'static, ()> > ,
+}
+
+
+impl<S, T> Throttle<S, T>
+{
+	pub(crate) fn new( stream: S, timer: T, period: Duration ) -> Self
+	{
+		Self{ stream, timer, period, sleep: None }
+	}
+}
+
+
+impl<S: Stream, T: Timer> Stream for Throttle<S, T>
+{
+	type Item = S::Item;
+
+	fn poll_next( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Option<Self::Item>>
+	{
+		let this = self.project();
+
+		if let Some( sleep ) = this.sleep
+		{
+			ready!( sleep.as_mut().poll( cx ) );
+
+			*this.sleep = None;
+		}
+
+		let item = ready!( this.stream.poll_next( cx ) );
+
+		if item.is_some()
+		{
+			*this.sleep = Some( this.timer.sleep( *this.period ) );
+		}
+
+		Poll::Ready( item )
+	}
+}
+
+
+
+/// A stream returned by [`TimerExt::debounce`](crate::TimerExt::debounce).
+///
+/// Yields an item once the stream has not produced a new one for `duration`. Items that are followed by
+/// another one within `duration` are dropped, so only the last of a burst comes through. When the stream
+/// ends, the last item is yielded right away.
+//
+#[ pin_project ]
+//
+pub struct Debounce<S: Stream, T>
+{
+	#[pin] stream  : S                                ,
+	       timer   : T                                ,
+	       duration: Duration                         ,
+	       sleep   : Option< BoxFuture<'static, ()> > ,
+	       last    : Option< S::Item >                ,
+	       done    : bool                             ,
+}
+
+
+impl<S: Stream, T> Debounce<S, T>
+{
+	pub(crate) fn new( stream: S, timer: T, duration: Duration ) -> Self
+	{
+		Self{ stream, timer, duration, sleep: None, last: None, done: false }
+	}
+}
+
+
+impl<S: Stream, T: Timer> Stream for Debounce<S, T>
+{
+	type Item = S::Item;
+
+	fn poll_next( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Option<Self::Item>>
+	{
+		let mut this   = self.project();
+		let mut budget = BUDGET;
+
+		while !*this.done
+		{
+			if budget == 0
+			{
+				cx.waker().wake_by_ref();
+				return Poll::Pending;
+			}
+
+			budget -= 1;
+
+			match this.stream.as_mut().poll_next( cx )
+			{
+				Poll::Ready( Some(item) ) =>
+				{
+					*this.last  = Some( item );
+					*this.sleep = Some( this.timer.sleep( *this.duration ) );
+				}
+
+				Poll::Ready( None ) => *this.done = true,
+				Poll::Pending       => break,
+			}
+		}
+
+		if *this.done
+		{
+			return Poll::Ready( this.last.take() );
+		}
+
+		match this.sleep
+		{
+			Some( sleep ) =>
+			{
+				ready!( sleep.as_mut().poll( cx ) );
+
+				*this.sleep = None;
+
+				Poll::Ready( this.last.take() )
+			}
+
+			None => Poll::Pending,
+		}
+	}
+}
+
+
+
+/// A stream returned by [`TimerExt::sample`](crate::TimerExt::sample).
+///
+/// Every `period`, yields the latest item the stream produced during that period. Periods without items
+/// yield nothing, and the other items are dropped. When the stream ends, the latest item is yielded
+/// right away.
+//
+#[ pin_project ]
+//
+pub struct Sample<S: Stream, T>
+{
+	#[pin] stream  : S                 ,
+	       interval: Interval<T>       ,
+	       last    : Option< S::Item > ,
+	       done    : bool              ,
+}
+
+
+impl<S: Stream, T: Timer> Sample<S, T>
+{
+	pub(crate) fn new( stream: S, interval: Interval<T> ) -> Self
+	{
+		Self{ stream, interval: interval.missed_ticks( MissedTicks::Skip ), last: None, done: false }
+	}
+}
+
+
+impl<S: Stream, T: Timer> Stream for Sample<S, T>
+{
+	type Item = S::Item;
+
+	fn poll_next( self: Pin<&mut Self>, cx: &mut Context<'_> ) -> Poll<Option<Self::Item>>
+	{
+		let mut this   = self.project();
+		let mut budget = BUDGET;
+
+		while !*this.done && budget > 0
+		{
+			budget -= 1;
+
+			match this.stream.as_mut().poll_next( cx )
+			{
+				Poll::Ready( Some(item) ) => *this.last = Some( item ),
+				Poll::Ready( None       ) => *this.done = true,
+				Poll::Pending             => break,
+			}
+		}
+
+		if *this.done
+		{
+			return Poll::Ready( this.last.take() );
+		}
+
+		// Skip ticks without an item, but keep polling the interval so it wakes us up.
+		//
+		while Pin::new( &mut *this.interval ).poll_next( cx ).is_ready()
+		{
+			if let Some( item ) = this.last.take()
+			{
+				return Poll::Ready( Some(item) );
+			}
+		}
+
+		// The stream is still ready, so it won't wake us.
+		//
+		if budget == 0
+		{
+			cx.waker().wake_by_ref();
+		}
+
+		Poll::Pending
+	}
+}
+
+
+
+impl<S, T> fmt::Debug for StreamTimeout<S, T>
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		f.debug_struct( "StreamTimeout" ).field( "duration", &self.duration ).finish()
+	}
+}
+
+
+impl<S, T> fmt::Debug for Throttle<S, T>
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		f.debug_struct( "Throttle" ).field( "period", &self.period ).finish()
+	}
+}
+
+
+impl<S: Stream, T> fmt::Debug for Debounce<S, T>
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		f.debug_struct( "Debounce" ).field( "duration", &self.duration ).finish()
+	}
+}
+
+
+impl<S: Stream, T: Timer> fmt::Debug for Sample<S, T>
+{
+	fn fmt( &self, f: &mut fmt::Formatter<'_> ) -> fmt::Result
+	{
+		f.debug_struct( "Sample" ).field( "period", &self.interval.period() ).finish()
+	}
+}
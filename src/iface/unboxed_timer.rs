@@ -0,0 +1,89 @@
+use
+{
+	std   :: { time::{ Duration, Instant }, future::Future } ,
+	super :: { Timer, Timeout                              } ,
+};
+
+
+/// A [`Timer`] that can return it's sleep future without boxing it.
+///
+/// [`Timer::sleep`] returns a `BoxFuture` so that `Timer` stays object safe, which costs an allocation for
+/// every sleep and every [`Timeout`]. In hot code, like a retry loop, require this trait instead and use
+/// [`sleep_unboxed`](UnboxedTimer::sleep_unboxed) and [`timeout_unboxed`](UnboxedTimer::timeout_unboxed),
+/// which return the sleep future of the underlying timer as is.
+///
+/// It's implemented by [`TokioCt`](crate::TokioCt), [`TokioTp`](crate::TokioTp), [`AsyncStd`](crate::AsyncStd)
+/// and [`AsyncGlobal`](crate::AsyncGlobal) on native targets, [`GlommioCt`](crate::GlommioCt),
+/// [`FuturesTimer`](crate::FuturesTimer) and [`TimerOnly`](crate::TimerOnly). Executor wrappers like
+/// [`Tracked`](crate::Tracked) forward it.
+///
+/// ```
+/// use async_executors::{ UnboxedTimer, TimeoutError };
+/// use std::time::Duration;
+///
+/// async fn retry( timer: impl UnboxedTimer, mut op: impl FnMut() -> Result<(), ()> ) -> Result<(), TimeoutError>
+/// {
+///    timer.timeout_unboxed( Duration::from_secs( 5 ), async
+///    {
+///       while op().is_err()
+///       {
+///          timer.sleep_unboxed( Duration::from_millis( 10 ) ).await;
+///       }
+///
+///    }).await
+/// }
+/// ```
+//
+#[ blanket::blanket( derive( Ref, Mut, Rc, Arc, Box ) ) ]
+//
+pub trait UnboxedTimer: Timer
+{
+	/// The future returned by [`sleep_unboxed`](UnboxedTimer::sleep_unboxed).
+	//
+	type Sleep: Future<Output = ()> + Send + 'static;
+
+
+	/// Like [`Timer::sleep`], without boxing the future.
+	//
+	#[ must_use = "sleep_unboxed() returns a future, which does nothing unless awaited" ]
+	//
+	fn sleep_unboxed( &self, dur: Duration ) -> Self::Sleep;
+
+
+	/// Like [`Timer::sleep_until`], without boxing the future.
+	///
+	/// The default sleeps for the time left until `deadline` according to [`current_time`](Timer::current_time).
+	//
+	#[ must_use = "sleep_until_unboxed() returns a future, which does nothing unless awaited" ]
+	//
+	fn sleep_until_unboxed( &self, deadline: Instant ) -> Self::Sleep
+	{
+		let dur = self.current_time().map_or( Duration::ZERO, |now| deadline.saturating_duration_since( now ) );
+
+		self.sleep_unboxed( dur )
+	}
+
+
+	/// Like [`TimerExt::timeout`](crate::TimerExt::timeout), without boxing the sleep.
+	//
+	#[ must_use = "timeout_unboxed() returns a future, which does nothing unless awaited." ]
+	//
+	fn timeout_unboxed<F: Future>( &self, duration: Duration, future: F ) -> Timeout<F, Self::Sleep>
+	{
+		let sleep_future = self.sleep_unboxed( duration );
+
+		Timeout { future, sleep_future }
+	}
+
+
+	/// Like [`TimerExt::timeout_at`](crate::TimerExt::timeout_at), without boxing the sleep.
+	//
+	#[ must_use = "timeout_at_unboxed() returns a future, which does nothing unless awaited." ]
+	//
+	fn timeout_at_unboxed<F: Future>( &self, deadline: Instant, future: F ) -> Timeout<F, Self::Sleep>
+	{
+		let sleep_future = self.sleep_until_unboxed( deadline );
+
+		Timeout { future, sleep_future }
+	}
+}
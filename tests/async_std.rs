@@ -26,9 +26,40 @@
 // ✔ Verify tokio_io works when the async_std_tokio feature is enabled.
 // ✔ Verify tokio_io doesn't work when the async_std_tokio feature is not enabled.
 // ✔ Verify Timeout future.
+// ✔ Verify Timeout future with a deadline.
+// ✔ Verify the timeouts of UnboxedTimer.
+// ✔ Verify the stream adapters of TimerExt.
 //
 // ✔ Joinhandle::detach allows task to keep running.
+// ✔ JoinHandle::try_join returns the output of the task.
+// ✔ JoinHandle::try_join returns JoinError::Panicked when the task panics.
+// ✔ JoinHandle::abort cancels the task.
+// ✔ AbortHandle can cancel the task from another task.
+// ✔ JoinHandle::is_finished is true once the task has completed.
+// ✔ JoinSet returns the output of all tasks.
+// ✔ JoinSet can hold !Send tasks.
+// ✔ JoinSet::abort_all cancels all tasks.
+// ✔ Dropping a JoinSet cancels the remaining tasks.
+// ✔ The id returned by the JoinHandle is the one seen from within the task.
+// ✔ Every task gets a different id.
+// ✔ A task spawned with a name can retrieve it, other tasks have no name.
+// ✔ A !Send task spawned with a name can retrieve it.
+// ✔ BlockOn runs the executor until the future completes.
+// ✔ BlockOn runs the executor until the future completes, with !Send tasks.
+// ✔ The name is passed on to async-std.
 // ✔ Joinhandle::drop aborts the task.
+// ✔ Tracked counts live tasks and wait_idle resolves once they have all finished or have been aborted.
+// ✔ Shutdown on Tracked refuses new tasks and waits for the running ones to finish.
+// ✔ Shutdown on Tracked aborts the tasks that are still running after the timeout.
+// ✔ Bounded refuses to spawn when saturated and spawn_handle_when_ready waits for a task to finish.
+// ✔ CancelScope::cancel aborts the tasks of the scope and it's children, with or without JoinHandle, and refuses new tasks.
+// ✔ Cancelling a child scope leaves the parent alone.
+// ✔ Tasks spawned through a CancelScope without a JoinHandle keep the kind of task id the executor gives them.
+// ✔ Tasks spawned through Tracked without a JoinHandle keep the kind of task id the executor gives them.
+// ✔ Metered counts spawned, completed, panicked and cancelled tasks and records poll durations.
+// ✔ Watchdog reports slow polls with the id, name and spawn location of the task.
+// ✔ CatchUnwind calls the hook with the panic and it's task, and resolves the JoinHandle with JoinError::Panicked.
+// ✔ MockTimer completes sleeps and timeouts only when it's ManualClock is advanced.
 //
 mod common;
 
@@ -424,6 +455,42 @@ fn run_timeout()
 
 
 
+helper_tests!
+{
+	|helper| AsyncStd::block_on( helper( AsyncStd ) );
+
+	run_timeout_at             => timeout_at                  ,
+	run_stream_timing          => stream_timing               ,
+	join_handle_try_join       => try_join_ok                 ,
+	join_handle_try_join_panic => try_join_panic              ,
+	join_handle_abort_explicit => abort_join_handle           ,
+	abort_handle_remote        => abort_from_other_task       ,
+	join_handle_is_finished    => is_finished_after_join      ,
+	join_set_spawn             => join_set                    ,
+	join_set_spawn_local       => join_set_local              ,
+	join_set_abort             => join_set_abort_all          ,
+	join_set_dropped           => join_set_drop               ,
+	task_id_join_handle        => task_id_matches             ,
+	task_id_distinct           => task_id_unique              ,
+	spawn_named                => spawn_named_task_name       ,
+	spawn_local_named          => spawn_local_named_task_name ,
+	tracked                    => tracked_wait_idle           ,
+	shutdown_drains            => shutdown_drain              ,
+	shutdown_aborts            => shutdown_abort              ,
+	bounded                    => bounded_backpressure        ,
+	cancel_scope_cancel        => cancel_scope                ,
+	cancel_scope_cancel_child  => cancel_scope_child          ,
+	cancel_scope_keeps_id      => cancel_scope_task_id        ,
+	tracked_keeps_id           => tracked_task_id             ,
+	metered                    => metered_counts              ,
+	watchdog                   => watchdog_slow_poll          ,
+	catch_unwind               => catch_unwind_hook           ,
+	mock_timer_advance         => mock_timer                  ,
+	run_unboxed_timeout        => unboxed_timeout             ,
+}
+
+
+
 // pass an AsyncStd to a function that requires a Timer.
 //
 #[ test ]
@@ -484,3 +551,33 @@ fn no_tokio_io()
 
 	AsyncStd::block_on( test );
 }
+
+
+
+// The name is passed on to async-std.
+//
+#[ test ]
+//
+fn spawn_named_native()
+{
+	let name = AsyncStd::block_on( async
+	{
+		AsyncStd.spawn_named( "worker", async
+		{
+			async_std_crate::task::current().name().map( str::to_string )
+
+		}).expect( "spawn" ).await
+	});
+
+	assert_eq!( name.as_deref(), Some( "worker" ) );
+}
+
+
+
+helper_tests!
+{
+	|helper| helper( AsyncStd );
+
+	block_on_trait       => block_on_spawn_handle       ,
+	block_on_trait_local => block_on_spawn_handle_local ,
+}
@@ -14,6 +14,47 @@ pub type DynResult<T>       = Result< T, Box<dyn std::error::Error + Send + Sync
 pub type DynResultNoSend<T> = Result< T, Box<dyn std::error::Error> >;
 
 
+// Generate a test for each helper below. The closure like head is the body of every test, with `helper`
+// bound to the helper of that test:
+//
+// helper_tests!
+// {
+//    |helper| AsyncStd::block_on( helper( AsyncStd ) );
+//
+//    join_handle_try_join => try_join_ok,
+//    #[ cfg( feature = "timer" ) ] run_timeout_at => timeout_at,
+// }
+//
+#[ allow( unused_macros ) ]
+//
+macro_rules! helper_tests
+{
+	(
+		| $helper:ident | $body:expr ;
+
+		$( $( #[ $attr:meta ] )* $name:ident => $fn:ident ),* $(,)?
+
+	) => { $(
+
+		$( #[ $attr ] )*
+		//
+		#[ test ]
+		//
+		fn $name()
+		{
+			let $helper = $fn;
+
+			$body;
+		}
+
+	)* };
+}
+
+#[ allow( unused_imports ) ]
+//
+pub(crate) use helper_tests;
+
+
 #[ cfg(not( target_arch = "wasm32" )) ]
 //
 pub mod tokio_io
@@ -399,3 +440,687 @@ pub async fn blocking_void( exec: &dyn SpawnBlocking<()> ) -> DynResult<()>
 
 	Ok(())
 }
+
+
+
+// A panicking task resolves `try_join` with the panic payload instead of unwinding.
+//
+pub async fn try_join_panic( exec: impl SpawnHandle<()> )
+{
+	let handle = exec.spawn_handle( async { panic!( "boom" ) } ).expect( "spawn" );
+
+	let err = handle.try_join().await.expect_err( "task panicked" );
+
+	assert!( err.is_panic() );
+	assert_eq!( err.panic_message(), Some( "boom" ) );
+}
+
+
+
+// A task that completes resolves `try_join` with it's output.
+//
+pub async fn try_join_ok( exec: impl SpawnHandle<u8> )
+{
+	let handle = exec.spawn_handle( sum_handle( 4, 1 ) ).expect( "spawn" );
+
+	assert_eq!( 5, handle.try_join().await.expect( "task completed" ) );
+}
+
+
+
+// JoinHandle::abort cancels the task, after which it is reported as finished.
+//
+pub async fn abort_join_handle( exec: impl SpawnHandle<()> )
+{
+	let handle   = exec.spawn_handle( futures::future::pending() ).expect( "spawn" );
+	let a_handle = handle.abort_handle();
+
+	assert!( !handle.is_finished() );
+
+	handle.abort();
+
+	let err = handle.try_join().await.expect_err( "task was aborted" );
+
+	assert!( err.is_cancelled()     );
+	assert!( a_handle.is_finished() );
+}
+
+
+
+// A clone of the AbortHandle can cancel the task from another task.
+//
+pub async fn abort_from_other_task( exec: impl SpawnHandle<()> )
+{
+	let handle   = exec.spawn_handle( futures::future::pending() ).expect( "spawn" );
+	let a_handle = handle.abort_handle().clone();
+
+	exec.spawn_handle( async move { a_handle.abort() } ).expect( "spawn" ).await;
+
+	let err = handle.try_join().await.expect_err( "task was aborted" );
+
+	assert!( err.is_cancelled() );
+}
+
+
+
+// JoinHandle::is_finished is true once the task has completed.
+//
+pub async fn is_finished_after_join( exec: impl SpawnHandle<()> )
+{
+	let (tx, rx) = oneshot::channel::<()>();
+
+	let handle   = exec.spawn_handle( async move { rx.await.expect( "receive" ); } ).expect( "spawn" );
+	let a_handle = handle.abort_handle();
+
+	assert!( !handle.is_finished() );
+
+	tx.send(()).expect( "send" );
+	handle.await;
+
+	assert!( a_handle.is_finished() );
+}
+
+
+
+// JoinSet returns the output of all tasks.
+//
+pub async fn join_set( exec: impl SpawnHandle<u8> )
+{
+	let mut set = JoinSet::new();
+
+	for i in 0..5
+	{
+		set.spawn( &exec, sum_handle( i, 1 ) ).expect( "spawn" );
+	}
+
+	assert_eq!( set.len(), 5 );
+
+	let mut outputs = Vec::new();
+
+	while let Some(out) = set.join_next().await
+	{
+		outputs.push( out.expect( "task completed" ) );
+	}
+
+	outputs.sort_unstable();
+
+	assert_eq!( outputs, vec![ 1, 2, 3, 4, 5 ] );
+	assert!( set.is_empty() );
+}
+
+
+
+// JoinSet can hold !Send tasks.
+//
+pub async fn join_set_local( exec: impl LocalSpawnHandle<Rc<u8>> )
+{
+	let mut set = JoinSet::new();
+
+	set.spawn_local( &exec, sum_handle_local( 4, 1 ) ).expect( "spawn" );
+
+	let out = set.join_next().await.expect( "some" ).expect( "task completed" );
+
+	assert_eq!( *out, 5 );
+	assert!( set.join_next().await.is_none() );
+}
+
+
+
+// JoinSet::abort_all cancels all tasks.
+//
+pub async fn join_set_abort_all( exec: impl SpawnHandle<()> )
+{
+	let mut set = JoinSet::new();
+
+	for _ in 0..3
+	{
+		set.spawn( &exec, futures::future::pending() ).expect( "spawn" );
+	}
+
+	set.abort_all();
+
+	let mut count = 0;
+
+	while let Some(out) = set.join_next().await
+	{
+		assert!( out.expect_err( "task was aborted" ).is_cancelled() );
+		count += 1;
+	}
+
+	assert_eq!( count, 3 );
+}
+
+
+
+// Dropping a JoinSet cancels the remaining tasks.
+//
+pub async fn join_set_drop( exec: impl SpawnHandle<()> )
+{
+	let (tx, rx) = oneshot::channel::<()>();
+	let mut set  = JoinSet::new();
+
+	set.spawn( &exec, async move
+	{
+		let _tx = tx;
+		futures::future::pending::<()>().await;
+
+	}).expect( "spawn" );
+
+	drop( set );
+
+	// The sender is dropped with the task.
+	//
+	assert!( rx.await.is_err() );
+}
+
+
+
+// The id returned by the JoinHandle is the one seen from within the task.
+//
+pub async fn task_id_matches( exec: impl SpawnHandle< Option<TaskId> > )
+{
+	let handle = exec.spawn_handle( async { task_id() } ).expect( "spawn" );
+	let id     = handle.id();
+
+	assert_eq!( handle.await, Some(id) );
+}
+
+
+
+// Every task gets a different id.
+//
+pub async fn task_id_unique( exec: impl SpawnHandle<()> )
+{
+	let a = exec.spawn_handle( async {} ).expect( "spawn" );
+	let b = exec.spawn_handle( async {} ).expect( "spawn" );
+
+	assert_ne!( a.id(), b.id() );
+	assert_eq!( a.id(), a.abort_handle().id() );
+
+	a.await;
+	b.await;
+}
+
+
+
+// A task spawned with a name can retrieve it, other tasks have no name.
+//
+pub async fn spawn_named_task_name( exec: impl SpawnHandle< Option<Arc<str>> > )
+{
+	let named   = exec.spawn_named( "worker", async { task_name() } ).expect( "spawn" );
+	let unnamed = exec.spawn_handle( async { task_name() } ).expect( "spawn" );
+
+	assert_eq!( named.await.as_deref(), Some( "worker" ) );
+	assert_eq!( unnamed.await         , None             );
+	assert_eq!( task_name()           , None             );
+}
+
+
+
+// A !Send task spawned with a name can retrieve it.
+//
+pub async fn spawn_local_named_task_name( exec: impl LocalSpawnHandle< Option<Arc<str>> > )
+{
+	let named = exec.spawn_local_named( "local_worker", async { task_name() } ).expect( "spawn" );
+
+	assert_eq!( named.await.as_deref(), Some( "local_worker" ) );
+}
+
+
+
+// BlockOn runs the executor until the future completes.
+//
+pub fn block_on_spawn_handle( exec: impl BlockOn + SpawnHandle<u8> )
+{
+	let out = exec.block_on( increment_spawn_handle( 4, &exec ) );
+
+	assert_eq!( out, 5 );
+}
+
+
+
+// BlockOn runs the executor until the future completes, with !Send tasks.
+//
+pub fn block_on_spawn_handle_local( exec: impl BlockOn + LocalSpawnHandle<Rc<u8>> )
+{
+	let out = exec.block_on( increment_spawn_handle_local( 4, &exec ) );
+
+	assert_eq!( *out, 5 );
+}
+
+
+
+// Shutdown on Tracked refuses new tasks and waits for the running ones to finish.
+//
+pub async fn shutdown_drain( exec: impl Timer + Spawn + SpawnHandle<()> )
+{
+	let exec     = Tracked::new( exec );
+	let (tx, rx) = oneshot::channel::<()>();
+
+	exec.spawn_handle( async move { rx.await.expect( "receive" ); } ).expect( "spawn" ).detach();
+
+	let shutdown = exec.shutdown( Duration::from_secs( 10 ) );
+
+	assert!( exec.spawn_handle( async {} ).expect_err( "spawn after shutdown" ).is_shutdown() );
+	assert!( exec.spawn       ( async {} ).expect_err( "spawn after shutdown" ).is_shutdown() );
+
+	tx.send(()).expect( "send" );
+
+	assert_eq!( shutdown.await, ShutdownReport{ drained: 1, aborted: 0 } );
+}
+
+
+
+// Shutdown on Tracked aborts the tasks that are still running after the timeout, also the ones
+// spawned without a JoinHandle.
+//
+pub async fn shutdown_abort( exec: impl Timer + Spawn + SpawnHandle<()> )
+{
+	let exec       = Tracked::new( exec );
+	let (tx , rx ) = oneshot::channel::<()>();
+	let (tx2, rx2) = oneshot::channel::<()>();
+
+	let handle = exec.spawn_handle( async move { rx.await.expect( "receive" ); } ).expect( "spawn" );
+
+	// The sender is dropped when the task is aborted.
+	//
+	exec.spawn( async move { let _tx2 = tx2; futures::future::pending::<()>().await; } ).expect( "spawn" );
+
+	let report = exec.shutdown( Duration::from_millis( 20 ) ).await;
+
+	assert_eq!( report, ShutdownReport{ drained: 0, aborted: 2 } );
+	assert!( matches!( handle.try_join().await, Err( JoinError::Cancelled ) ) );
+	assert!( rx2.await.is_err() );
+
+	exec.wait_idle().await;
+
+	drop( tx );
+}
+
+
+
+// Tasks spawned through Tracked without a JoinHandle keep the kind of task id the executor gives them.
+//
+pub async fn tracked_task_id( exec: impl Spawn + Clone )
+{
+	let (tx , rx ) = oneshot::channel();
+	let (tx2, rx2) = oneshot::channel();
+
+	Tracked::new( exec.clone() ).spawn( async move { tx.send( task_id() ).expect( "send" ); } ).expect( "spawn" );
+	exec                        .spawn( async move { tx2.send( task_id() ).expect( "send" ); } ).expect( "spawn" );
+
+	let is_tokio = |id: TaskId| id.to_string().starts_with( "tokio-" );
+
+	let tracked = rx .await.expect( "receive" );
+	let direct = rx2.await.expect( "receive" );
+
+	assert_eq!( tracked.map( is_tokio ), direct.map( is_tokio ) );
+}
+
+
+
+// Tracked counts live tasks and wait_idle resolves once they have all finished or have been aborted.
+//
+pub async fn tracked_wait_idle( exec: impl Spawn + SpawnHandle<()> )
+{
+	let exec = Tracked::new( exec );
+
+	let (tx , rx ) = oneshot::channel::<()>();
+	let (tx2, rx2) = oneshot::channel::<()>();
+
+	exec.spawn_handle( async move { rx.await.expect( "receive" ); } ).expect( "spawn" ).detach();
+	exec.spawn       ( async move { rx2.await.expect( "receive" ); } ).expect( "spawn" );
+
+	let pending = exec.spawn_handle( futures::future::pending() ).expect( "spawn" );
+
+	assert_eq!( exec.active_tasks(), 3 );
+
+	let idle = exec.wait_idle();
+
+	tx .send(()).expect( "send" );
+	tx2.send(()).expect( "send" );
+	pending.abort();
+
+	idle.await;
+
+	assert_eq!( exec.active_tasks(), 0 );
+}
+
+
+
+// Bounded refuses to spawn when saturated and spawn_handle_when_ready waits for a task to finish.
+//
+pub async fn bounded_backpressure( exec: impl SpawnHandle<()> )
+{
+	let exec     = Bounded::new( exec, 1 );
+	let (tx, rx) = oneshot::channel::<()>();
+
+	let first = exec.spawn_handle( async move { rx.await.expect( "receive" ); } ).expect( "spawn" );
+
+	assert_eq!( exec.available(), 0 );
+	assert!( exec.spawn_handle( async {} ).is_err() );
+
+	let second = exec.spawn_handle_when_ready( async {} );
+	futures::pin_mut!( second );
+
+	assert!( futures::poll!( &mut second ).is_pending() );
+
+	tx.send(()).expect( "send" );
+	first.await;
+
+	second.await.expect( "spawn" ).await;
+
+	assert_eq!( exec.available(), 1 );
+}
+
+
+
+// CancelScope::cancel aborts the tasks of the scope and it's children, with or without JoinHandle, and refuses new tasks.
+//
+pub async fn cancel_scope( exec: impl Spawn + SpawnHandle<()> + Clone )
+{
+	let scope = CancelScope::new( exec );
+	let child = scope.child();
+
+	// The sender is dropped when the task is aborted.
+	//
+	let (tx, rx) = oneshot::channel::<()>();
+
+	scope.spawn( async move { let _tx = tx; futures::future::pending::<()>().await } ).expect( "spawn" );
+
+	let handle = child.spawn_handle( futures::future::pending() ).expect( "spawn" );
+
+	scope.cancel();
+
+	assert!( rx.await.is_err() );
+	assert!( matches!( handle.try_join().await, Err( JoinError::Cancelled ) ) );
+
+	assert!( child.is_cancelled() );
+	assert!( scope.spawn       ( async {} ).expect_err( "spawn" ).is_shutdown() );
+	assert!( child.spawn_handle( async {} ).expect_err( "spawn" ).is_shutdown() );
+}
+
+
+
+// Cancelling a child scope leaves the parent alone.
+//
+pub async fn cancel_scope_child( exec: impl SpawnHandle<u8> + Clone )
+{
+	let scope = CancelScope::new( exec );
+
+	scope.child().cancel();
+
+	assert!( !scope.is_cancelled() );
+	assert_eq!( scope.spawn_handle( async { 5 } ).expect( "spawn" ).await, 5 );
+}
+
+
+
+// Tasks spawned through a CancelScope without a JoinHandle keep the kind of task id the executor gives them.
+//
+pub async fn cancel_scope_task_id( exec: impl Spawn + Clone )
+{
+	let (tx , rx ) = oneshot::channel();
+	let (tx2, rx2) = oneshot::channel();
+
+	CancelScope::new( exec.clone() ).spawn( async move { tx.send( task_id() ).expect( "send" ); } ).expect( "spawn" );
+	exec                            .spawn( async move { tx2.send( task_id() ).expect( "send" ); } ).expect( "spawn" );
+
+	let is_tokio = |id: TaskId| id.to_string().starts_with( "tokio-" );
+
+	let scoped = rx .await.expect( "receive" );
+	let direct = rx2.await.expect( "receive" );
+
+	assert_eq!( scoped.map( is_tokio ), direct.map( is_tokio ) );
+}
+
+
+
+// Metered counts how tasks end and records how long they are polled.
+//
+pub async fn metered_counts( exec: impl Spawn + SpawnHandle<()> )
+{
+	let exec = Metered::new( exec );
+
+	let (tx , rx ) = oneshot::channel::<()>();
+	let (tx2, rx2) = oneshot::channel::<()>();
+	let (tx3, rx3) = oneshot::channel::<()>();
+
+	exec.spawn( async move { tx.send(()).expect( "send" ); } ).expect( "spawn" );
+	rx.await.expect( "receive" );
+
+	exec.spawn_handle( async { std::thread::sleep( Duration::from_millis( 5 ) ) } ).expect( "spawn" ).await;
+
+	let panics = exec.spawn_handle( async { panic!( "boom" ) } ).expect( "spawn" );
+
+	assert!( panics.try_join().await.expect_err( "task panicked" ).is_panic() );
+
+	// The second sender is dropped when the task is aborted.
+	//
+	let pending = exec.spawn_handle( async move
+	{
+		let _tx2 = tx2;
+		tx3.send(()).expect( "send" );
+		futures::future::pending::<()>().await;
+
+	}).expect( "spawn" );
+
+	rx3.await.expect( "receive" );
+
+	assert_eq!( exec.snapshot().active(), 1 );
+
+	pending.abort();
+
+	assert!( rx2.await.is_err() );
+
+	let metrics = exec.snapshot();
+
+	assert_eq!( metrics.spawned  , 4 );
+	assert_eq!( metrics.completed, 2 );
+	assert_eq!( metrics.panicked , 1 );
+	assert_eq!( metrics.cancelled, 1 );
+	assert_eq!( metrics.active() , 0 );
+
+	assert!( metrics.poll_duration .count() >= 4 );
+	assert!( metrics.schedule_delay.count() >= 4 );
+
+	assert!( metrics.poll_duration.max() >= Duration::from_millis( 5 ) );
+	assert!( metrics.poll_duration.quantile( 1.0 ) >= Some( Duration::from_millis( 5 ) ) );
+	assert_eq!( metrics.poll_duration.buckets().map( |(_, n)| n ).sum::<u64>(), metrics.poll_duration.count() );
+}
+
+
+
+// Watchdog reports polls over the threshold with the id, name and spawn location of the task.
+//
+pub async fn watchdog_slow_poll( exec: impl SpawnHandle<()> )
+{
+	let reports  = Arc::new( std::sync::Mutex::new( Vec::<SlowPoll>::new() ) );
+	let reports2 = reports.clone();
+
+	let exec = Watchdog::new( exec, Duration::from_millis( 10 ), move |slow|
+	{
+		reports2.lock().expect( "lock" ).push( slow.clone() );
+	});
+
+	exec.spawn_handle( async {} ).expect( "spawn" ).await;
+
+	let line   = line!() + 1;
+	let handle = exec.spawn_named( "slow", async { std::thread::sleep( Duration::from_millis( 20 ) ) } ).expect( "spawn" );
+	let id     = handle.id();
+
+	handle.await;
+
+	let reports = reports.lock().expect( "lock" );
+
+	assert_eq!( reports.len(), 1 );
+	assert!   ( reports[0].duration >= Duration::from_millis( 20 ) );
+	assert_eq!( reports[0].task_id, Some(id) );
+	assert_eq!( reports[0].task_name.as_deref(), Some( "slow" ) );
+	assert_eq!( reports[0].location.file(), file!() );
+	assert_eq!( reports[0].location.line(), line );
+}
+
+
+
+// CatchUnwind calls the hook with the panic and it's task, resolves the JoinHandle with JoinError::Panicked
+// and keeps the executor running.
+//
+pub async fn catch_unwind_hook( exec: impl Spawn + SpawnHandle<()> )
+{
+	use futures::{ channel::mpsc, StreamExt };
+
+	let (tx, mut rx) = mpsc::unbounded::<( Option<String>, Option<TaskId>, Option<Arc<str>> )>();
+
+	let exec = CatchUnwind::new( exec, move |panic|
+	{
+		let report = ( panic.message().map( String::from ), panic.task_id, panic.task_name.clone() );
+
+		tx.unbounded_send( report ).expect( "send" );
+	});
+
+	exec.spawn( async { panic!( "detached" ) } ).expect( "spawn" );
+
+	let (msg, _, name) = rx.next().await.expect( "hook called" );
+
+	assert_eq!( msg.as_deref(), Some( "detached" ) );
+	assert!   ( name.is_none() );
+
+	let handle = exec.spawn_named( "named", async { panic!( "boom" ) } ).expect( "spawn" );
+	let id     = handle.id();
+	let err    = handle.try_join().await.expect_err( "task panicked" );
+
+	assert_eq!( err.panic_message(), Some( "boom" ) );
+
+	let (msg, task, name) = rx.next().await.expect( "hook called" );
+
+	assert_eq!( msg.as_deref() , Some( "boom"  ) );
+	assert_eq!( task           , Some( id      ) );
+	assert_eq!( name.as_deref(), Some( "named" ) );
+
+	// The JoinHandle of the wrapped executor is returned, so aborting works as usual.
+	//
+	let pending = exec.spawn_handle( futures::future::pending::<()>() ).expect( "spawn" );
+
+	pending.abort();
+
+	assert!( matches!( pending.try_join().await, Err( JoinError::Cancelled ) ) );
+
+	exec.spawn_handle( async {} ).expect( "spawn" ).await;
+}
+
+
+
+// MockTimer completes sleeps and timeouts only when it's ManualClock is advanced.
+//
+pub async fn mock_timer( exec: impl SpawnHandle<bool> )
+{
+	let clock = ManualClock::new();
+	let exec  = MockTimer::new( exec, clock.clone() );
+
+	let late  = exec.timeout( Duration::from_secs( 60 ), futures::future::pending::<()>()     );
+	let early = exec.timeout( Duration::from_secs( 90 ), exec.sleep( Duration::from_secs(30) ) );
+
+	let late  = exec.spawn_handle( async move { late .await.is_err() } ).expect( "spawn" );
+	let early = exec.spawn_handle( async move { early.await.is_ok()  } ).expect( "spawn" );
+
+	assert_eq!( clock.pending_timers(), 3 );
+
+	clock.advance( Duration::from_secs( 20 ) );
+	clock.advance( Duration::from_secs( 10 ) );
+
+	assert!( early.await );
+
+	// The timeout of early got dropped along with it.
+	//
+	assert_eq!( clock.pending_timers(), 1 );
+	assert_eq!( clock.advance_to_next_timer(), Some( Duration::from_secs( 60 ) ) );
+
+	assert!( late.await );
+
+	assert_eq!( clock.pending_timers(), 0 );
+	assert_eq!( clock.advance_to_next_timer(), None );
+	assert_eq!( clock.now(), Duration::from_secs( 60 ) );
+}
+
+
+
+// Interval yields ticks on a real timer.
+//
+pub async fn interval_ticks( exec: impl Timer + Clone )
+{
+	use futures::StreamExt;
+
+	let ticks = exec.interval( Duration::from_millis(5) ).take( 3 ).count().await;
+
+	assert_eq!( ticks, 3 );
+}
+
+
+
+// Use timeout_at with a deadline shared by several operations.
+//
+pub async fn timeout_at( exec: impl Timer )
+{
+	let deadline = std::time::Instant::now() + Duration::from_millis(20);
+
+	let fut = exec.sleep     ( Duration::from_millis(80)  );
+	let fut = exec.timeout_at( deadline, fut              );
+
+	assert!( fut.await.is_err() );
+	assert!( std::time::Instant::now() >= deadline );
+
+	// The deadline has passed, so this times out right away.
+	//
+	let fut = exec.timeout_at( deadline, futures::future::pending::<()>() );
+
+	assert!( fut.await.is_err() );
+
+	exec.sleep_until( deadline ).await;
+}
+
+
+
+// The stream adapters of TimerExt work on a real timer.
+//
+pub async fn stream_timing( exec: impl Timer + Clone )
+{
+	use futures::{ stream, StreamExt };
+
+	let items: Vec<_> = exec.throttle( Duration::from_millis(5), stream::iter( [1, 2, 3] ) ).collect().await;
+	assert_eq!( items, [1, 2, 3] );
+
+	let items: Vec<_> = exec.debounce( Duration::from_millis(5), stream::iter( [1, 2, 3] ) ).collect().await;
+	assert_eq!( items, [3] );
+
+	let items: Vec<_> = exec.sample( Duration::from_millis(5), stream::iter( [1, 2, 3] ) ).collect().await;
+	assert_eq!( items, [3] );
+
+	let items: Vec<_> = exec.stream_timeout( Duration::from_millis(5), stream::pending::<u8>() ).take( 2 ).collect().await;
+	assert_eq!( items, [ Err(TimeoutError), Err(TimeoutError) ] );
+}
+
+
+
+// Use the timeouts of UnboxedTimer.
+//
+pub async fn unboxed_timeout( exec: impl UnboxedTimer )
+{
+	let fut = exec.sleep_unboxed  ( Duration::from_millis(80)      );
+	let fut = exec.timeout_unboxed( Duration::from_millis(20), fut );
+
+	assert!( fut.await.is_err() );
+
+	let fut = exec.sleep_unboxed  ( Duration::from_millis(20)      );
+	let fut = exec.timeout_unboxed( Duration::from_millis(80), fut );
+
+	assert!( fut.await.is_ok() );
+
+	let deadline = std::time::Instant::now() + Duration::from_millis(20);
+	let fut      = exec.timeout_at_unboxed( deadline, futures::future::pending::<()>() );
+
+	assert!( fut.await.is_err() );
+	assert!( std::time::Instant::now() >= deadline );
+
+	exec.sleep_until_unboxed( deadline ).await;
+}
@@ -0,0 +1,215 @@
+#![ cfg(all( feature = "tokio_tp", feature = "async_std", feature = "threadpool", feature = "localpool", not(target_os = "unknown") )) ]
+//
+// ✔ pass a DynExec with TokioTp      to a function that takes exec: `impl SpawnHandle`
+// ✔ pass a DynExec with AsyncStd     to a function that takes exec: `impl LocalSpawnHandle`
+// ✔ pass a DynExec with LocalSpawner to a function that takes exec: `impl LocalSpawnHandle`
+// ✔ LocalSpawnHandle returns an error on TokioTp and ThreadPool.
+// ✔ LocalSpawn returns an error on TokioTp and ThreadPool.
+// ✔ can_spawn_local tells which executors support LocalSpawn.
+// ✔ pass a DynExec with TokioTp      to a function that requires a SpawnBlocking.
+// ✔ pass a DynExec with ThreadPool   to a function that requires a SpawnBlocking (runs on a dedicated thread).
+// ✔ pass a DynExec with ThreadPool   to a function that requires an object safe SpawnBlocking.
+// ✔ pass a DynExec with AsyncStd     to a function that requires YieldNow.
+// ✔ pass a DynExec with ThreadPool   to a function that requires a Timer.
+// ✔ Task names are passed on to the active executor.
+// ✔ Spawning after the runtime has shut down returns SpawnError::shutdown.
+//
+mod common;
+
+use
+{
+	common           :: { *                   } ,
+	futures_executor :: { LocalPool           } ,
+	futures::task    :: { LocalSpawnExt       } ,
+	std              :: { rc::Rc              } ,
+};
+
+
+// pass a DynExec with TokioTp to a function that takes exec: `impl SpawnHandle`
+//
+#[ test ]
+//
+fn spawn_handle_tokio_tp()
+{
+	let exec   = TokioTp::new().expect( "create tokio threadpool" );
+	let result = exec.block_on( increment_spawn_handle( 4, DynExec::from( exec.clone() ) ) );
+
+	assert_eq!( 5u8, result );
+}
+
+
+
+// pass a DynExec with AsyncStd to a function that takes exec: `impl LocalSpawnHandle`
+//
+#[ test ]
+//
+fn spawn_handle_local_async_std()
+{
+	let result = AsyncStd::block_on( increment_spawn_handle_local( 4, DynExec::from( AsyncStd ) ) );
+
+	assert_eq!( 5u8, *result );
+}
+
+
+
+// pass a DynExec with LocalSpawner to a function that takes exec: `impl LocalSpawnHandle`
+//
+#[ test ]
+//
+fn spawn_handle_local_localpool()
+{
+	let mut pool = LocalPool::new();
+	let     exec = DynExec::from( pool.spawner() );
+
+	let result = pool.run_until( increment_spawn_handle_local( 4, exec ) );
+
+	assert_eq!( 5u8, *result );
+}
+
+
+
+// LocalSpawnHandle returns an error on TokioTp and ThreadPool.
+//
+#[ test ]
+//
+fn spawn_handle_local_unsupported()
+{
+	let tokio = DynExec::from( TokioTp::new().expect( "create tokio threadpool" ) );
+	let pool  = DynExec::from( ThreadPool::new().expect( "create threadpool" ) );
+
+	assert!( tokio.spawn_handle_local( async { Rc::new( 5u8 ) } ).is_err() );
+	assert!( pool .spawn_handle_local( async { Rc::new( 5u8 ) } ).is_err() );
+}
+
+
+
+// LocalSpawn returns an error on TokioTp and ThreadPool.
+//
+#[ test ]
+//
+fn spawn_local_unsupported()
+{
+	let tokio = DynExec::from( TokioTp::new().expect( "create tokio threadpool" ) );
+	let pool  = DynExec::from( ThreadPool::new().expect( "create threadpool" ) );
+
+	assert!( tokio.spawn_local( async {} ).is_err() );
+	assert!( pool .spawn_local( async {} ).is_err() );
+}
+
+
+
+// can_spawn_local tells which executors support LocalSpawn.
+//
+#[ test ]
+//
+fn can_spawn_local()
+{
+	assert!( !DynExec::from( TokioTp::new().expect( "create tokio threadpool" ) ).can_spawn_local() );
+	assert!( !DynExec::from( ThreadPool::new().expect( "create threadpool" )    ).can_spawn_local() );
+	assert!(  DynExec::from( AsyncStd                                           ).can_spawn_local() );
+	assert!(  DynExec::from( LocalPool::new().spawner()                         ).can_spawn_local() );
+}
+
+
+
+// pass a DynExec with TokioTp to a function that requires a SpawnBlocking.
+//
+#[ test ]
+//
+fn spawn_blocking_tokio_tp() -> DynResult<()>
+{
+	let exec = TokioTp::new().expect( "create tokio threadpool" );
+
+	exec.block_on( blocking( DynExec::from( exec.clone() ) ) )
+}
+
+
+
+// pass a DynExec with ThreadPool to a function that requires a SpawnBlocking.
+//
+#[ test ]
+//
+fn spawn_blocking_threadpool() -> DynResult<()>
+{
+	let exec = DynExec::from( ThreadPool::new().expect( "create threadpool" ) );
+
+	block_on( blocking( exec ) )
+}
+
+
+
+// pass a DynExec with ThreadPool to a function that requires an object safe SpawnBlocking.
+//
+#[ test ]
+//
+fn spawn_blocking_void_threadpool() -> DynResult<()>
+{
+	let exec = DynExec::from( ThreadPool::new().expect( "create threadpool" ) );
+
+	block_on( blocking_void( &exec ) )
+}
+
+
+
+// pass a DynExec with AsyncStd to a function that requires YieldNow.
+//
+#[ test ]
+//
+fn yield_run_subtask_first() -> DynResult<()>
+{
+	let exec = DynExec::from( AsyncStd );
+
+	AsyncStd::block_on( try_yield_now( exec ) )
+}
+
+
+
+// pass a DynExec with ThreadPool to a function that requires a Timer.
+//
+#[ cfg( feature = "timer" ) ]
+//
+#[ test ]
+//
+fn timer_threadpool()
+{
+	let exec = DynExec::from( ThreadPool::new().expect( "create threadpool" ) );
+
+	block_on( timeout     ( &exec ) );
+	block_on( dont_timeout( &exec ) );
+}
+
+
+
+// Task names are passed on to the active executor.
+//
+#[ test ]
+//
+fn spawn_named_async_std()
+{
+	let name = AsyncStd::block_on( async
+	{
+		DynExec::from( AsyncStd ).spawn_named( "worker", async
+		{
+			async_std_crate::task::current().name().map( str::to_string )
+
+		}).expect( "spawn" ).await
+	});
+
+	assert_eq!( name.as_deref(), Some( "worker" ) );
+}
+
+
+
+// Spawning after the runtime has shut down returns SpawnError::shutdown.
+//
+#[ test ]
+//
+fn spawn_after_runtime_shutdown()
+{
+	let rt   = tokio::runtime::Builder::new_multi_thread().build().expect( "create tokio runtime" );
+	let exec = DynExec::from( TokioTp::try_from( rt.handle().clone() ).expect( "create from handle" ) );
+
+	drop( rt );
+
+	assert!( exec.spawn_handle( async {} ).expect_err( "spawn_handle" ).is_shutdown() );
+}
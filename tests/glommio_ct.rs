@@ -25,8 +25,40 @@
 
 // ✔ pass an GommioCt to a function that requires a Timer.
 // ✔ Verify Timeout future.
+// ✔ Verify Timeout future with a deadline.
+// ✔ Verify the timeouts of UnboxedTimer.
+// ✔ Verify the stream adapters of TimerExt.
+// ✔ A sleep counts from it's creation.
+// ✔ A sleep panics when polled outside of block_on before it's deadline.
 //
 // ✔ Joinhandle::detach allows task to keep running.
+// ✔ JoinHandle::try_join returns the output of the task.
+// ✔ JoinHandle::try_join returns JoinError::Panicked when the task panics.
+// ✔ JoinHandle::abort cancels the task.
+// ✔ AbortHandle can cancel the task from another task.
+// ✔ JoinHandle::is_finished is true once the task has completed.
+// ✔ JoinSet returns the output of all tasks.
+// ✔ JoinSet can hold !Send tasks.
+// ✔ JoinSet::abort_all cancels all tasks.
+// ✔ Dropping a JoinSet cancels the remaining tasks.
+// ✔ The id returned by the JoinHandle is the one seen from within the task.
+// ✔ Every task gets a different id.
+// ✔ A task spawned with a name can retrieve it, other tasks have no name.
+// ✔ A !Send task spawned with a name can retrieve it.
+// ✔ BlockOn runs the executor until the future completes.
+// ✔ BlockOn runs the executor until the future completes, with !Send tasks.
+// ✔ Shutdown on Tracked refuses new tasks and waits for the running ones to finish.
+// ✔ Shutdown on Tracked aborts the tasks that are still running after the timeout.
+// ✔ Bounded refuses to spawn when saturated and spawn_handle_when_ready waits for a task to finish.
+// ✔ Spawning outside of block_on returns SpawnError::shutdown instead of panicking.
+// ✔ CancelScope::cancel aborts the tasks of the scope and it's children, with or without JoinHandle, and refuses new tasks.
+// ✔ Cancelling a child scope leaves the parent alone.
+// ✔ Tasks spawned through a CancelScope without a JoinHandle keep the kind of task id the executor gives them.
+// ✔ Tasks spawned through Tracked without a JoinHandle keep the kind of task id the executor gives them.
+// ✔ Metered counts spawned, completed, panicked and cancelled tasks and records poll durations.
+// ✔ Watchdog reports slow polls with the id, name and spawn location of the task.
+// ✔ CatchUnwind calls the hook with the panic and it's task, and resolves the JoinHandle with JoinError::Panicked.
+// ✔ MockTimer completes sleeps and timeouts only when it's ManualClock is advanced.
 // - Test cpu pinning.
 // - What happens if we make a nested call to block_on
 // - What happens if we call exec constructor again inside block_on.
@@ -38,7 +70,7 @@ use
 	common        :: * ,
 	futures       :: { channel::{ mpsc }, StreamExt    } ,
 	glommio_crate :: { LocalExecutorBuilder, Placement } ,
-	std           :: { rc::Rc                          } ,
+	std           :: { rc::Rc, time::Instant           } ,
 };
 
 
@@ -532,8 +564,6 @@ async fn without_yield_now_glommio( exec: impl LocalSpawnHandle<()> + YieldNow +
 
 // pass an GommioCt to a function that requires a Timer.
 //
-#[ cfg( feature = "timer" ) ]
-//
 #[ test ]
 //
 fn timer_should_wake_local()
@@ -546,8 +576,6 @@ fn timer_should_wake_local()
 
 // pass an GlommioCt to a function that requires a Timer.
 //
-#[ cfg( feature = "timer" ) ]
-//
 #[ test ]
 //
 fn run_timeout()
@@ -559,9 +587,90 @@ fn run_timeout()
 
 
 
-// pass an GlommioCt to a function that requires a Timer.
+helper_tests!
+{
+	|helper|
+	{
+		let exec = GlommioCt::new( LocalExecutorBuilder::new( Placement::Unbound ) ).expect( "create exec" );
+
+		exec.block_on( helper( exec.clone() ) )
+	};
+
+	run_timeout_at             => timeout_at                  ,
+	run_stream_timing          => stream_timing               ,
+	join_handle_try_join       => try_join_ok                 ,
+	join_handle_try_join_panic => try_join_panic              ,
+	join_handle_abort_explicit => abort_join_handle           ,
+	abort_handle_remote        => abort_from_other_task       ,
+	join_handle_is_finished    => is_finished_after_join      ,
+	join_set_spawn             => join_set                    ,
+	join_set_spawn_local       => join_set_local              ,
+	join_set_abort             => join_set_abort_all          ,
+	join_set_dropped           => join_set_drop               ,
+	task_id_join_handle        => task_id_matches             ,
+	task_id_distinct           => task_id_unique              ,
+	spawn_named                => spawn_named_task_name       ,
+	spawn_local_named          => spawn_local_named_task_name ,
+	shutdown_drains            => shutdown_drain              ,
+	shutdown_aborts            => shutdown_abort              ,
+	bounded                    => bounded_backpressure        ,
+	cancel_scope_cancel        => cancel_scope                ,
+	cancel_scope_cancel_child  => cancel_scope_child          ,
+	cancel_scope_keeps_id      => cancel_scope_task_id        ,
+	tracked_keeps_id           => tracked_task_id             ,
+	metered                    => metered_counts              ,
+	watchdog                   => watchdog_slow_poll          ,
+	catch_unwind               => catch_unwind_hook           ,
+	mock_timer_advance         => mock_timer                  ,
+}
+
+
+
+// A sleep counts from it's creation.
+//
+#[ test ]
 //
-#[ cfg( feature = "timer" ) ]
+fn sleep_deadline()
+{
+	let exec = &GlommioCt::new( LocalExecutorBuilder::new( Placement::Unbound ) ).expect( "create exec" );
+
+	let mut outside = exec.sleep( Duration::from_millis( 1 ) );
+	std::thread::sleep( Duration::from_millis( 5 ) );
+
+	// The deadline has passed, so this completes without an executor.
+	//
+	assert!( (&mut outside).now_or_never().is_some() );
+
+	exec.block_on( async
+	{
+		let sleep = exec.sleep( Duration::from_millis( 50 ) );
+		std::thread::sleep( Duration::from_millis( 50 ) );
+
+		let start = Instant::now();
+		sleep.await;
+
+		assert!( start.elapsed() < Duration::from_millis( 40 ) );
+	});
+}
+
+
+
+// A sleep panics when polled outside of block_on before it's deadline.
+//
+#[ test ]
+//
+#[ should_panic( expected = "must be polled within GlommioCt::block_on" ) ]
+//
+fn sleep_outside_block_on()
+{
+	let exec = &GlommioCt::new( LocalExecutorBuilder::new( Placement::Unbound ) ).expect( "create exec" );
+
+	let _ = exec.sleep( Duration::from_secs( 60 ) ).now_or_never();
+}
+
+
+
+// pass an GlommioCt to a function that requires a Timer.
 //
 #[ test ]
 //
@@ -571,3 +680,55 @@ fn run_dont_timeout()
 
 	exec.block_on( dont_timeout( exec ) );
 }
+
+
+
+helper_tests!
+{
+	|helper| helper( GlommioCt::new( LocalExecutorBuilder::new( Placement::Unbound ) ).expect( "create exec" ) );
+
+	block_on_trait       => block_on_spawn_handle       ,
+	block_on_trait_local => block_on_spawn_handle_local ,
+}
+
+
+
+// Spawning outside of block_on returns SpawnError::shutdown instead of panicking.
+//
+#[ test ]
+//
+fn spawn_outside_block_on()
+{
+	let builder = LocalExecutorBuilder::new( Placement::Unbound );
+	let exec    = GlommioCt::new( builder ).expect( "create exec" );
+
+	assert!( exec.spawn( async {} ).expect_err( "spawn" ).is_shutdown() );
+	assert!( exec.spawn_local( async {} ).expect_err( "spawn_local" ).is_shutdown() );
+	assert!( exec.spawn_handle( async {} ).expect_err( "spawn_handle" ).is_shutdown() );
+	assert!( exec.spawn_handle_local( async { Rc::new(()) } ).expect_err( "spawn_handle_local" ).is_shutdown() );
+
+	// Spawning works once block_on runs and fails again after it returned.
+	//
+	exec.block_on( async { exec.spawn_handle( async {} ).expect( "spawn_handle" ).await } );
+
+	assert!( exec.spawn_handle( async {} ).expect_err( "spawn_handle" ).is_shutdown() );
+
+	// Another glommio executor running on this thread doesn't count.
+	//
+	let other = GlommioCt::new( LocalExecutorBuilder::new( Placement::Unbound ) ).expect( "create exec" );
+
+	other.block_on( async { assert!( exec.spawn_handle( async {} ).expect_err( "spawn_handle" ).is_shutdown() ) } );
+}
+
+
+
+// Verify the timeouts of UnboxedTimer.
+//
+#[ test ]
+//
+fn run_unboxed_timeout()
+{
+	let exec = &GlommioCt::new( LocalExecutorBuilder::new( Placement::Unbound ) ).expect( "create exec" );
+
+	exec.block_on( unboxed_timeout( exec ) );
+}
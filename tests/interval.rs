@@ -0,0 +1,146 @@
+// Tested:
+//
+// ✔ Interval yields a tick every period.
+// ✔ Interval with MissedTicks::Burst yields all missed ticks and keeps the schedule.
+// ✔ Interval with MissedTicks::Delay yields one tick and shifts the schedule.
+// ✔ Interval with MissedTicks::Skip yields one tick and keeps the schedule.
+// ✔ Interval panics when the period is zero.
+//
+use
+{
+	async_executors :: { Interval, ManualClock, MissedTicks, TimerExt } ,
+	futures         :: { FutureExt, StreamExt                         } ,
+	std             :: { time::Duration                               } ,
+};
+
+
+// Count the ticks that are ready without waiting.
+//
+fn ticks( interval: &mut Interval<ManualClock> ) -> usize
+{
+	let mut n = 0;
+
+	while interval.next().now_or_never().is_some()
+	{
+		n += 1;
+	}
+
+	n
+}
+
+
+fn secs( n: u64 ) -> Duration
+{
+	Duration::from_secs( n )
+}
+
+
+
+// Interval yields a tick every period.
+//
+#[ test ]
+//
+fn interval_ticks()
+{
+	let clock        = ManualClock::new();
+	let mut interval = clock.interval( secs(10) );
+
+	assert_eq!( ticks( &mut interval ), 0 );
+
+	clock.advance( secs(9) );
+	assert_eq!( ticks( &mut interval ), 0 );
+
+	clock.advance( secs(1) );
+	assert_eq!( ticks( &mut interval ), 1 );
+
+	assert_eq!( clock.advance_to_next_timer(), Some( secs(20) ) );
+	assert_eq!( ticks( &mut interval ), 1 );
+}
+
+
+
+// Interval with MissedTicks::Burst yields all missed ticks and keeps the schedule.
+//
+#[ test ]
+//
+fn interval_burst()
+{
+	let clock        = ManualClock::new();
+	let mut interval = clock.interval( secs(10) ).missed_ticks( MissedTicks::Burst );
+
+	clock.advance( secs(10) );
+	assert_eq!( ticks( &mut interval ), 1 );
+
+	// Ticks at 20, 30 and 40.
+	//
+	clock.advance( secs(35) );
+	assert_eq!( ticks( &mut interval ), 3 );
+
+	clock.advance( secs(5) );
+	assert_eq!( ticks( &mut interval ), 1 );
+}
+
+
+
+// Interval with MissedTicks::Delay yields one tick and shifts the schedule.
+//
+#[ test ]
+//
+fn interval_delay()
+{
+	let clock        = ManualClock::new();
+	let mut interval = clock.interval( secs(10) ).missed_ticks( MissedTicks::Delay );
+
+	clock.advance( secs(10) );
+	assert_eq!( ticks( &mut interval ), 1 );
+
+	clock.advance( secs(35) );
+	assert_eq!( ticks( &mut interval ), 1 );
+
+	// The next tick is a period after the late one, at 55.
+	//
+	clock.advance( secs(5) );
+	assert_eq!( ticks( &mut interval ), 0 );
+
+	clock.advance( secs(5) );
+	assert_eq!( ticks( &mut interval ), 1 );
+}
+
+
+
+// Interval with MissedTicks::Skip yields one tick and keeps the schedule.
+//
+#[ test ]
+//
+fn interval_skip()
+{
+	let clock        = ManualClock::new();
+	let mut interval = clock.interval( secs(10) ).missed_ticks( MissedTicks::Skip );
+
+	clock.advance( secs(10) );
+	assert_eq!( ticks( &mut interval ), 1 );
+
+	clock.advance( secs(35) );
+	assert_eq!( ticks( &mut interval ), 1 );
+
+	// The ticks at 30 and 40 are skipped, the next one is at 50.
+	//
+	clock.advance( secs(5) );
+	assert_eq!( ticks( &mut interval ), 1 );
+
+	assert_eq!( clock.advance_to_next_timer(), Some( secs(60) ) );
+	assert_eq!( ticks( &mut interval ), 1 );
+}
+
+
+
+// Interval panics when the period is zero.
+//
+#[ test ]
+//
+#[ should_panic( expected = "period can not be zero" ) ]
+//
+fn interval_zero_period()
+{
+	let _interval = ManualClock::new().interval( Duration::ZERO );
+}
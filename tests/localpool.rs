@@ -14,6 +14,40 @@
 // ✔ Verify LocalPool    does not implement Timer when feature is not enabled.
 // ✔ Verify LocalSpawner does not implement Timer when feature is not enabled.
 // ✔ Verify Timeout future.
+// ✔ Verify Timeout future with a deadline.
+// ✔ JoinHandle::try_join returns the output of the task.
+// ✔ JoinHandle::try_join returns JoinError::Panicked when the task panics.
+// ✔ JoinHandle::abort cancels the task.
+// ✔ AbortHandle can cancel the task from another task.
+// ✔ JoinHandle::is_finished is true once the task has completed.
+// ✔ JoinSet returns the output of all tasks.
+// ✔ JoinSet can hold !Send tasks.
+// ✔ JoinSet::abort_all cancels all tasks.
+// ✔ Dropping a JoinSet cancels the remaining tasks.
+// ✔ The id returned by the JoinHandle is the one seen from within the task.
+// ✔ Every task gets a different id.
+// ✔ A task spawned with a name can retrieve it, other tasks have no name.
+// ✔ A !Send task spawned with a name can retrieve it.
+// ✔ BlockOn runs the executor until the future completes.
+// ✔ BlockOn runs the executor until the future completes, with !Send tasks.
+// ✔ LocalPoolExec::run runs spawned tasks to completion and clones share the pool.
+// ✔ task_id returns None outside of a task.
+// ✔ JoinHandle::try_join returns JoinError::Cancelled when the executor is dropped.
+// ✔ Shutdown on Tracked refuses new tasks and waits for the running ones to finish.
+// ✔ Shutdown on Tracked aborts the tasks that are still running after the timeout.
+// ✔ Tracked counts live tasks and wait_idle resolves once they have all finished or have been aborted.
+// ✔ Bounded refuses to spawn when saturated and spawn_handle_when_ready waits for a task to finish.
+// ✔ Spawning after the LocalPool has been dropped returns SpawnError::shutdown.
+// ✔ CancelScope::cancel aborts the tasks of the scope and it's children, with or without JoinHandle, and refuses new tasks.
+// ✔ Cancelling a child scope leaves the parent alone.
+// ✔ Tasks spawned through a CancelScope without a JoinHandle keep the kind of task id the executor gives them.
+// ✔ Tasks spawned through Tracked without a JoinHandle keep the kind of task id the executor gives them.
+// ✔ Metered counts spawned, completed, panicked and cancelled tasks and records poll durations.
+// ✔ Watchdog reports slow polls with the id, name and spawn location of the task.
+// ✔ CatchUnwind calls the hook with the panic and it's task, and resolves the JoinHandle with JoinError::Panicked.
+// ✔ MockTimer completes sleeps and timeouts only when it's ManualClock is advanced.
+// ✔ pass a LocalSpawner to a function that uses an Interval.
+// ✔ pass a TimerOnly around a LocalSpawner to a function that requires a Timer.
 //
 mod common;
 
@@ -182,6 +216,37 @@ fn run_timeout()
 
 
 
+helper_tests!
+{
+	|helper|
+	{
+		let mut pool = LocalPool::new();
+		let     exec = pool.spawner();
+
+		pool.run_until( helper( exec ) )
+	};
+
+	#[ cfg( feature = "timer" ) ]
+	run_timeout_at             => timeout_at                  ,
+	join_handle_try_join       => try_join_ok                 ,
+	join_handle_try_join_panic => try_join_panic              ,
+	join_handle_abort_explicit => abort_join_handle           ,
+	abort_handle_remote        => abort_from_other_task       ,
+	join_handle_is_finished    => is_finished_after_join      ,
+	join_set_spawn             => join_set                    ,
+	join_set_spawn_local       => join_set_local              ,
+	join_set_abort             => join_set_abort_all          ,
+	join_set_dropped           => join_set_drop               ,
+	task_id_join_handle        => task_id_matches             ,
+	task_id_distinct           => task_id_unique              ,
+	spawn_named                => spawn_named_task_name       ,
+	spawn_local_named          => spawn_local_named_task_name ,
+	#[ cfg( feature = "timer" ) ]
+	interval                   => interval_ticks              ,
+}
+
+
+
 // pass an LocalSpawner to a function that requires a Timer.
 //
 #[ cfg( feature = "timer" ) ]
@@ -210,3 +275,135 @@ fn no_feature_no_timer()
 	static_assertions::assert_not_impl_any!( LocalSpawner: Timer );
 }
 
+
+
+// JoinHandle::try_join returns JoinError::Cancelled when the executor is dropped.
+//
+#[ test ]
+//
+fn join_handle_try_join_cancelled()
+{
+	let exec    = LocalPool::new();
+	let spawner = exec.spawner();
+	let handle  = spawner.spawn_handle( futures::future::pending::<()>() ).expect( "spawn" );
+
+	drop( exec );
+
+	let err = block_on( handle.try_join() ).expect_err( "task was canceled" );
+
+	assert!( err.is_cancelled() );
+}
+
+
+
+// task_id returns None outside of a task.
+//
+#[ test ]
+//
+fn task_id_outside_task()
+{
+	assert!( task_id().is_none() );
+}
+
+
+
+helper_tests!
+{
+	|helper| helper( LocalPoolExec::new() );
+
+	block_on_trait       => block_on_spawn_handle       ,
+	block_on_trait_local => block_on_spawn_handle_local ,
+}
+
+
+
+// LocalPoolExec::run runs spawned tasks to completion and clones share the pool.
+//
+#[ test ]
+//
+fn local_pool_exec_run()
+{
+	use futures::{ channel::mpsc, StreamExt };
+
+	let exec     = LocalPoolExec::new();
+	let (tx, rx) = mpsc::channel( 1 );
+
+	increment( 4, exec.clone(), tx );
+	exec.run();
+
+	let result = exec.block_on( rx.take( 1 ).next() ).expect( "Some" );
+
+	assert_eq!( 5u8, result );
+}
+
+
+
+helper_tests!
+{
+	|helper|
+	{
+		let exec = LocalPoolExec::new();
+
+		exec.block_on( helper( exec.clone() ) )
+	};
+
+	#[ cfg( feature = "timer" ) ]
+	shutdown_drains => shutdown_drain ,
+	#[ cfg( feature = "timer" ) ]
+	shutdown_aborts => shutdown_abort ,
+}
+
+
+
+helper_tests!
+{
+	|helper|
+	{
+		let exec = LocalPoolExec::new();
+
+		exec.block_on( helper( exec.spawner() ) )
+	};
+
+	tracked                   => tracked_wait_idle    ,
+	bounded                   => bounded_backpressure ,
+	cancel_scope_cancel       => cancel_scope         ,
+	cancel_scope_cancel_child => cancel_scope_child   ,
+	cancel_scope_keeps_id     => cancel_scope_task_id ,
+	tracked_keeps_id          => tracked_task_id      ,
+	metered                   => metered_counts       ,
+	watchdog                  => watchdog_slow_poll   ,
+	catch_unwind              => catch_unwind_hook    ,
+	mock_timer_advance        => mock_timer           ,
+}
+
+
+
+// Spawning after the LocalPool has been dropped returns SpawnError::shutdown.
+//
+#[ test ]
+//
+fn spawn_after_pool_dropped()
+{
+	let exec = LocalPool::new().spawner();
+
+	assert!( exec.spawn             ( async {}            ).expect_err( "spawn"              ).is_shutdown() );
+	assert!( exec.spawn_local       ( async {}            ).expect_err( "spawn_local"        ).is_shutdown() );
+	assert!( exec.spawn_handle      ( async {}            ).expect_err( "spawn_handle"       ).is_shutdown() );
+	assert!( exec.spawn_handle_local( async { Rc::new(()) } ).expect_err( "spawn_handle_local" ).is_shutdown() );
+}
+
+
+
+// pass a TimerOnly around a LocalSpawner to a function that requires a Timer.
+//
+#[ cfg( feature = "timer" ) ]
+//
+#[ test ]
+//
+fn timer_only()
+{
+	let mut wrap = LocalPool::new();
+	let     exec = TimerOnly::new( wrap.spawner() );
+
+	wrap.run_until( timer_should_wake_up_local( exec ) );
+}
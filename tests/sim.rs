@@ -0,0 +1,340 @@
+#![ cfg( feature = "sim" ) ]
+//
+// Tested:
+//
+// ✔ pass a SimExec to a function that takes exec: `impl Spawn`
+// ✔ pass a SimExec to a function that takes exec: `impl LocalSpawn`
+// ✔ pass a SimExec to a function that takes exec: `impl SpawnHandle`
+// ✔ pass a SimExec to a function that takes exec: `impl LocalSpawnHandle`
+// ✔ pass a SimExec to a function that requires a Timer.
+// ✔ pass a SimExec to a function that requires a SpawnBlocking.
+// ✔ pass a SimExec to a function that requires an object safe SpawnBlocking.
+// ✔ Verify Timeout future.
+// ✔ JoinHandle::try_join returns JoinError::Panicked when the task panics.
+// ✔ JoinHandle::abort cancels the task.
+// ✔ JoinSet returns the output of all tasks.
+// ✔ The id returned by the JoinHandle is the one seen from within the task.
+// ✔ Sleeping advances virtual time without taking real time.
+// ✔ Timers fire in the order of their deadlines.
+// ✔ The same seed gives the same order of execution, different seeds give different orders.
+// ✔ run runs all tasks until none can make progress.
+// ✔ block_on panics when the future can not make progress.
+// ✔ An Interval on virtual time bursts through missed ticks without waiting.
+// ✔ Deadlines are on the virtual clock.
+//
+mod common;
+
+use
+{
+	common  :: { *                        } ,
+	futures :: { channel::mpsc, StreamExt } ,
+	std     :: { cell::RefCell            } ,
+};
+
+
+// pass a SimExec to a function that takes exec: `impl Spawn`
+//
+#[ test ]
+//
+fn spawn()
+{
+	let (tx, mut rx) = mpsc::channel( 1 );
+	let exec         = SimExec::new( 1 );
+
+	increment( 4, exec.clone(), tx );
+
+	let result = exec.block_on( rx.next() ).expect( "Some" );
+
+	assert_eq!( 5u8, result );
+}
+
+
+
+// pass a SimExec to a function that takes exec: `impl LocalSpawn`
+//
+#[ test ]
+//
+fn spawn_local()
+{
+	let (tx, mut rx) = mpsc::channel( 1 );
+	let exec         = SimExec::new( 1 );
+
+	increment_local( 4, exec.clone(), tx );
+
+	let result = exec.block_on( rx.next() ).expect( "Some" );
+
+	assert_eq!( 5u8, result );
+}
+
+
+
+// pass a SimExec to a function that takes exec: `impl SpawnHandle`
+//
+#[ test ]
+//
+fn spawn_handle()
+{
+	let exec   = SimExec::new( 1 );
+	let result = exec.block_on( increment_spawn_handle( 4, exec.clone() ) );
+
+	assert_eq!( 5u8, result );
+}
+
+
+
+// pass a SimExec to a function that takes exec: `impl LocalSpawnHandle`
+//
+#[ test ]
+//
+fn spawn_handle_local()
+{
+	let exec   = SimExec::new( 1 );
+	let result = exec.block_on( increment_spawn_handle_local( 4, exec.clone() ) );
+
+	assert_eq!( 5u8, *result );
+}
+
+
+
+helper_tests!
+{
+	|helper|
+	{
+		let exec = SimExec::new( 1 );
+
+		exec.block_on( helper( exec.clone() ) )
+	};
+
+	timer_should_wake          => timer_should_wake_up_local ,
+	join_handle_try_join_panic => try_join_panic             ,
+	join_handle_abort_explicit => abort_join_handle          ,
+	join_set_spawn             => join_set                   ,
+	task_id_join_handle        => task_id_matches            ,
+}
+
+
+
+// pass a SimExec to a function that requires a SpawnBlocking.
+//
+#[ test ]
+//
+fn spawn_blocking() -> DynResult<()>
+{
+	let exec = SimExec::new( 1 );
+
+	exec.block_on( blocking( exec.clone() ) )
+}
+
+
+
+// pass a SimExec to a function that requires an object safe SpawnBlocking.
+//
+#[ test ]
+//
+fn spawn_blocking_void() -> DynResult<()>
+{
+	let exec = SimExec::new( 1 );
+
+	exec.block_on( blocking_void( &exec ) )
+}
+
+
+
+// Verify Timeout future.
+//
+#[ test ]
+//
+fn run_timeout()
+{
+	let exec = SimExec::new( 1 );
+
+	exec.block_on( timeout( exec.clone() ) );
+	exec.block_on( dont_timeout( exec.clone() ) );
+}
+
+
+
+// Sleeping advances virtual time without taking real time.
+//
+#[ test ]
+//
+fn virtual_time()
+{
+	let exec  = SimExec::new( 1 );
+	let start = std::time::Instant::now();
+
+	exec.block_on( exec.sleep( Duration::from_secs( 3600 ) ) );
+
+	assert_eq!( exec.now(), Duration::from_secs( 3600 ) );
+	assert!( start.elapsed() < Duration::from_secs( 10 ) );
+}
+
+
+
+// Timers fire in the order of their deadlines.
+//
+#[ test ]
+//
+fn timer_order()
+{
+	for seed in 0..20
+	{
+		let exec  = SimExec::new( seed );
+		let fired = Rc::new( RefCell::new( Vec::new() ) );
+
+		for secs in [ 3, 1, 2 ]
+		{
+			let exec  = exec.clone();
+			let fired = fired.clone();
+
+			exec.clone().spawn_local( async move
+			{
+				exec.sleep( Duration::from_secs( secs ) ).await;
+				fired.borrow_mut().push( ( secs, exec.now() ) );
+
+			}).expect( "spawn" );
+		}
+
+		exec.run();
+
+		let expect: Vec<_> = [ 1, 2, 3 ].into_iter().map( |s| ( s, Duration::from_secs(s) ) ).collect();
+
+		assert_eq!( *fired.borrow(), expect );
+	}
+}
+
+
+
+// Record the order in which tasks that yield a few times finish.
+//
+fn order( seed: u64 ) -> Vec<u8>
+{
+	let exec = SimExec::new( seed );
+	let done = Rc::new( RefCell::new( Vec::new() ) );
+
+	for i in 0..8
+	{
+		let exec2 = exec.clone();
+		let done  = done.clone();
+
+		exec.spawn_local( async move
+		{
+			for _ in 0..3 { exec2.yield_now().await; }
+
+			done.borrow_mut().push( i );
+
+		}).expect( "spawn" );
+	}
+
+	exec.run();
+
+	done.take()
+}
+
+
+
+// The same seed gives the same order of execution, different seeds give different orders.
+//
+#[ test ]
+//
+fn seeded_order()
+{
+	assert_eq!( order( 42 ), order( 42 ) );
+
+	let orders: std::collections::HashSet<_> = ( 0..20 ).map( order ).collect();
+
+	assert!( orders.len() > 1 );
+}
+
+
+
+// run runs all tasks until none can make progress.
+//
+#[ test ]
+//
+fn run_all()
+{
+	let exec     = SimExec::new( 1 );
+	let (tx, rx) = oneshot::channel::<()>();
+	let flag     = Rc::new( RefCell::new( false ) );
+	let flag2    = flag.clone();
+
+	// Never completes, run should not wait for it.
+	//
+	exec.spawn_local( async move { let _ = rx.await; } ).expect( "spawn" );
+	exec.spawn_local( async move { *flag2.borrow_mut() = true; } ).expect( "spawn" );
+
+	exec.run();
+
+	assert!( *flag.borrow() );
+	drop( tx );
+}
+
+
+
+// block_on panics when the future can not make progress.
+//
+#[ test ]
+//
+#[ should_panic( expected = "can not make progress" ) ]
+//
+fn block_on_stalled()
+{
+	let exec = SimExec::new( 1 );
+
+	exec.block_on( futures::future::pending::<()>() );
+}
+
+
+
+// An Interval on virtual time bursts through missed ticks without waiting.
+//
+#[ test ]
+//
+fn interval_virtual_time()
+{
+	let exec = SimExec::new( 1 );
+
+	exec.block_on( async
+	{
+		let mut interval = exec.interval( Duration::from_secs( 10 ) );
+
+		interval.next().await;
+		exec.sleep( Duration::from_secs( 25 ) ).await;
+
+		// The ticks at 20 and 30 are ready right away.
+		//
+		interval.next().await;
+		interval.next().await;
+
+		assert_eq!( exec.now(), Duration::from_secs( 35 ) );
+
+		interval.next().await;
+
+		assert_eq!( exec.now(), Duration::from_secs( 40 ) );
+	});
+}
+
+
+
+// Deadlines are on the virtual clock.
+//
+#[ test ]
+//
+fn virtual_deadline()
+{
+	let exec     = SimExec::new( 1 );
+	let deadline = exec.current_time().expect( "clock" ) + Duration::from_secs( 60 );
+
+	exec.block_on( async
+	{
+		exec.sleep_until( deadline - Duration::from_secs( 30 ) ).await;
+
+		assert_eq!( exec.now(), Duration::from_secs( 30 ) );
+
+		let res = exec.timeout_at( deadline, futures::future::pending::<()>() ).await;
+
+		assert!( res.is_err() );
+		assert_eq!( exec.now(), Duration::from_secs( 60 ) );
+	});
+}
@@ -5,10 +5,28 @@
 // ✔ pass a    &ThreadPool  to a function that takes exec: `&dyn SpawnHandle`
 //
 // ✔ Joinhandle::detach allows task to keep running.
+// ✔ JoinHandle::try_join returns the output of the task.
+// ✔ JoinHandle::try_join returns JoinError::Panicked when the task panics.
+// ✔ JoinHandle::abort cancels the task.
+// ✔ AbortHandle can cancel the task from another task.
+// ✔ JoinHandle::is_finished is true once the task has completed.
+// ✔ JoinSet returns the output of all tasks.
+// ✔ JoinSet::abort_all cancels all tasks.
+// ✔ Dropping a JoinSet cancels the remaining tasks.
+// ✔ The id returned by the JoinHandle is the one seen from within the task.
+// ✔ Every task gets a different id.
+// ✔ A task spawned with a name can retrieve it, other tasks have no name.
+// ✔ BlockOn runs the executor until the future completes.
 //
 // ✔ pass an ThreadPool to a function that requires a Timer.
 // ✔ Verify ThreadPool does not implement Timer when feature is not enabled.
 // ✔ Verify Timeout future.
+// ✔ Verify Timeout future with a deadline.
+// ✔ pass a ThreadPool to a function that uses an Interval.
+// ✔ Shutdown on Tracked refuses new tasks and waits for the running ones to finish.
+// ✔ Shutdown on Tracked aborts the tasks that are still running after the timeout.
+// ✔ pass a TimerOnly around a ThreadPool to a function that requires a Timer.
+// ✔ FuturesTimer works without an executor.
 //
 mod common;
 
@@ -121,6 +139,33 @@ fn run_timeout()
 
 
 
+helper_tests!
+{
+	|helper| block_on( helper( ThreadPool::new().expect( "create threadpool" ) ) );
+
+	#[ cfg( feature = "timer" ) ]
+	run_timeout_at             => timeout_at             ,
+	join_handle_try_join       => try_join_ok            ,
+	join_handle_try_join_panic => try_join_panic         ,
+	join_handle_abort_explicit => abort_join_handle      ,
+	abort_handle_remote        => abort_from_other_task  ,
+	join_handle_is_finished    => is_finished_after_join ,
+	join_set_spawn             => join_set               ,
+	join_set_abort             => join_set_abort_all     ,
+	join_set_dropped           => join_set_drop          ,
+	task_id_join_handle        => task_id_matches        ,
+	task_id_distinct           => task_id_unique         ,
+	spawn_named                => spawn_named_task_name  ,
+	#[ cfg( feature = "timer" ) ]
+	interval                   => interval_ticks         ,
+	#[ cfg( feature = "timer" ) ]
+	shutdown_drains            => shutdown_drain         ,
+	#[ cfg( feature = "timer" ) ]
+	shutdown_aborts            => shutdown_abort         ,
+}
+
+
+
 // pass an ThreadPool to a function that requires a Timer.
 //
 #[ cfg( feature = "timer" ) ]
@@ -146,3 +191,45 @@ fn no_feature_no_timer()
 {
 	static_assertions::assert_not_impl_any!( ThreadPool: Timer );
 }
+
+
+
+// BlockOn runs the executor until the future completes.
+//
+#[ test ]
+//
+fn block_on_trait()
+{
+	let exec = ThreadPool::new().expect( "create threadpool" );
+
+	block_on_spawn_handle( exec );
+}
+
+
+
+// pass a TimerOnly around a ThreadPool to a function that requires a Timer.
+//
+#[ cfg( feature = "timer" ) ]
+//
+#[ test ]
+//
+fn timer_only()
+{
+	let exec = TimerOnly::new( ThreadPool::new().expect( "create threadpool" ) );
+
+	block_on( timer_should_wake_up( exec ) );
+}
+
+
+
+// FuturesTimer works without an executor.
+//
+#[ cfg( feature = "timer" ) ]
+//
+#[ test ]
+//
+fn futures_timer()
+{
+	block_on( timeout     ( FuturesTimer ) );
+	block_on( dont_timeout( FuturesTimer ) );
+}
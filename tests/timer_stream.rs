@@ -0,0 +1,189 @@
+// Tested:
+//
+// ✔ stream_timeout yields an error every time the stream is quiet for too long.
+// ✔ throttle spaces out items without dropping them.
+// ✔ debounce only yields the last item of a burst.
+// ✔ sample yields the latest item of each period.
+// ✔ debounce and sample return to the executor when the stream is always ready.
+//
+use
+{
+	async_executors :: { ManualClock, TimerExt, TimeoutError         } ,
+	futures         :: { channel::mpsc, stream, FutureExt, StreamExt } ,
+	std             :: { time::Duration                      } ,
+};
+
+
+// Collect the items that are ready without waiting. The outer None means the stream has ended.
+//
+fn ready<S: futures::Stream + Unpin>( stream: &mut S ) -> Vec< Option<S::Item> >
+{
+	let mut items = Vec::new();
+
+	while let Some( item ) = stream.next().now_or_never()
+	{
+		let end = item.is_none();
+
+		items.push( item );
+
+		if end { break }
+	}
+
+	items
+}
+
+
+fn secs( n: u64 ) -> Duration
+{
+	Duration::from_secs( n )
+}
+
+
+
+// stream_timeout yields an error every time the stream is quiet for too long.
+//
+#[ test ]
+//
+fn stream_timeout()
+{
+	let clock      = ManualClock::new();
+	let (tx, rx)   = mpsc::unbounded();
+	let mut stream = clock.stream_timeout( secs(10), rx );
+
+	assert!( ready( &mut stream ).is_empty() );
+
+	tx.unbounded_send( 1 ).expect( "send" );
+	assert_eq!( ready( &mut stream ), vec![ Some( Ok(1) ) ] );
+
+	clock.advance( secs(10) );
+	assert_eq!( ready( &mut stream ), vec![ Some( Err(TimeoutError) ) ] );
+
+	clock.advance( secs(5) );
+	tx.unbounded_send( 2 ).expect( "send" );
+	assert_eq!( ready( &mut stream ), vec![ Some( Ok(2) ) ] );
+
+	// The timer restarted with the item.
+	//
+	clock.advance( secs(9) );
+	assert!( ready( &mut stream ).is_empty() );
+
+	drop( tx );
+	assert_eq!( ready( &mut stream ), vec![ None ] );
+}
+
+
+
+// throttle spaces out items without dropping them.
+//
+#[ test ]
+//
+fn throttle()
+{
+	let clock      = ManualClock::new();
+	let (tx, rx)   = mpsc::unbounded();
+	let mut stream = clock.throttle( secs(10), rx );
+
+	for i in 1..=3 { tx.unbounded_send( i ).expect( "send" ); }
+
+	assert_eq!( ready( &mut stream ), vec![ Some(1) ] );
+
+	clock.advance( secs(10) );
+	assert_eq!( ready( &mut stream ), vec![ Some(2) ] );
+
+	clock.advance( secs(5) );
+	assert!( ready( &mut stream ).is_empty() );
+
+	clock.advance( secs(5) );
+	assert_eq!( ready( &mut stream ), vec![ Some(3) ] );
+
+	drop( tx );
+	assert!( ready( &mut stream ).is_empty() );
+
+	clock.advance( secs(10) );
+	assert_eq!( ready( &mut stream ), vec![ None ] );
+}
+
+
+
+// debounce only yields the last item of a burst.
+//
+#[ test ]
+//
+fn debounce()
+{
+	let clock      = ManualClock::new();
+	let (tx, rx)   = mpsc::unbounded();
+	let mut stream = clock.debounce( secs(10), rx );
+
+	tx.unbounded_send( 1 ).expect( "send" );
+	tx.unbounded_send( 2 ).expect( "send" );
+	assert!( ready( &mut stream ).is_empty() );
+
+	clock.advance( secs(5) );
+	tx.unbounded_send( 3 ).expect( "send" );
+	assert!( ready( &mut stream ).is_empty() );
+
+	clock.advance( secs(9) );
+	assert!( ready( &mut stream ).is_empty() );
+
+	clock.advance( secs(1) );
+	assert_eq!( ready( &mut stream ), vec![ Some(3) ] );
+
+	// The last item comes through right away when the stream ends.
+	//
+	tx.unbounded_send( 4 ).expect( "send" );
+	drop( tx );
+	assert_eq!( ready( &mut stream ), vec![ Some(4), None ] );
+}
+
+
+
+// sample yields the latest item of each period.
+//
+#[ test ]
+//
+fn sample()
+{
+	let clock      = ManualClock::new();
+	let (tx, rx)   = mpsc::unbounded();
+	let mut stream = clock.sample( secs(10), rx );
+
+	tx.unbounded_send( 1 ).expect( "send" );
+	tx.unbounded_send( 2 ).expect( "send" );
+	assert!( ready( &mut stream ).is_empty() );
+
+	clock.advance( secs(10) );
+	assert_eq!( ready( &mut stream ), vec![ Some(2) ] );
+
+	// No item in this period.
+	//
+	clock.advance( secs(10) );
+	assert!( ready( &mut stream ).is_empty() );
+
+	tx.unbounded_send( 3 ).expect( "send" );
+	clock.advance( secs(10) );
+	assert_eq!( ready( &mut stream ), vec![ Some(3) ] );
+
+	tx.unbounded_send( 4 ).expect( "send" );
+	drop( tx );
+	assert_eq!( ready( &mut stream ), vec![ Some(4), None ] );
+}
+
+
+
+// debounce and sample return to the executor when the stream is always ready.
+//
+#[ test ]
+//
+fn always_ready_stream()
+{
+	let clock        = ManualClock::new();
+	let mut debounce = clock.debounce( secs(10), stream::repeat( 1 ) );
+	let mut sample   = clock.sample  ( secs(10), stream::repeat( 1 ) );
+
+	assert!( debounce.next().now_or_never().is_none() );
+	assert!( sample  .next().now_or_never().is_none() );
+
+	clock.advance( secs(10) );
+	assert_eq!( sample.next().now_or_never(), Some( Some(1) ) );
+}
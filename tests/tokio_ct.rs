@@ -30,11 +30,43 @@
 // ✔ pass a TokioCt to a function that requires a Timer.
 // ✔ Verify TokioCt does not implement Timer when feature is not enabled.
 // ✔ Verify Timeout future.
+// ✔ Verify Timeout future with a deadline.
+// ✔ Verify the timeouts of UnboxedTimer.
+// ✔ Verify the stream adapters of TimerExt.
 //
 // ✔ Verify tokio_io works        when the tokio_io feature is     enabled.
 // ✔ Verify tokio_io doesn't work when the tokio_io feature is not enabled.
 //
 // ✔ Joinhandle::detach allows task to keep running.
+// ✔ JoinHandle::try_join returns the output of the task.
+// ✔ JoinHandle::try_join returns JoinError::Panicked when the task panics.
+// ✔ JoinHandle::abort cancels the task.
+// ✔ AbortHandle can cancel the task from another task.
+// ✔ JoinHandle::is_finished is true once the task has completed.
+// ✔ JoinSet returns the output of all tasks.
+// ✔ JoinSet can hold !Send tasks.
+// ✔ JoinSet::abort_all cancels all tasks.
+// ✔ Dropping a JoinSet cancels the remaining tasks.
+// ✔ The id returned by the JoinHandle is the one seen from within the task.
+// ✔ Every task gets a different id.
+// ✔ Tokio ids are displayed with a prefix, so they can't be mistaken for ids generated by the crate.
+// ✔ A task spawned with a name can retrieve it, other tasks have no name.
+// ✔ A !Send task spawned with a name can retrieve it.
+// ✔ BlockOn runs the executor until the future completes.
+// ✔ BlockOn runs the executor until the future completes, with !Send tasks.
+// ✔ JoinHandle::try_join returns JoinError::Cancelled when the executor is dropped.
+// ✔ Shutdown on Tracked refuses new tasks and waits for the running ones to finish.
+// ✔ Shutdown on Tracked aborts the tasks that are still running after the timeout.
+// ✔ Bounded refuses to spawn when saturated and spawn_handle_when_ready waits for a task to finish.
+// ✔ Spawning after the runtime has shut down returns SpawnError::shutdown.
+// ✔ CancelScope::cancel aborts the tasks of the scope and it's children, with or without JoinHandle, and refuses new tasks.
+// ✔ Cancelling a child scope leaves the parent alone.
+// ✔ Tasks spawned through a CancelScope without a JoinHandle keep the kind of task id the executor gives them.
+// ✔ Tasks spawned through Tracked without a JoinHandle keep the kind of task id the executor gives them.
+// ✔ Metered counts spawned, completed, panicked and cancelled tasks and records poll durations.
+// ✔ Watchdog reports slow polls with the id, name and spawn location of the task.
+// ✔ CatchUnwind calls the hook with the panic and it's task, and resolves the JoinHandle with JoinError::Panicked.
+// ✔ MockTimer completes sleeps and timeouts only when it's ManualClock is advanced.
 //
 mod common;
 
@@ -563,6 +595,51 @@ fn run_timeout()
 
 
 
+helper_tests!
+{
+	|helper|
+	{
+		let exec = TokioCt::new().expect( "create tokio current thread" );
+
+		exec.block_on( helper( exec.clone() ) )
+	};
+
+	#[ cfg(any( feature="timer", feature="tokio_timer" )) ]
+	run_timeout_at             => timeout_at                  ,
+	#[ cfg(any( feature="timer", feature="tokio_timer" )) ]
+	run_stream_timing          => stream_timing               ,
+	join_handle_try_join       => try_join_ok                 ,
+	join_handle_try_join_panic => try_join_panic              ,
+	join_handle_abort_explicit => abort_join_handle           ,
+	abort_handle_remote        => abort_from_other_task       ,
+	join_handle_is_finished    => is_finished_after_join      ,
+	join_set_spawn             => join_set                    ,
+	join_set_spawn_local       => join_set_local              ,
+	join_set_abort             => join_set_abort_all          ,
+	join_set_dropped           => join_set_drop               ,
+	task_id_join_handle        => task_id_matches             ,
+	task_id_distinct           => task_id_unique              ,
+	spawn_named                => spawn_named_task_name       ,
+	spawn_local_named          => spawn_local_named_task_name ,
+	#[ cfg(any( feature="timer", feature="tokio_timer" )) ]
+	shutdown_drains            => shutdown_drain              ,
+	#[ cfg(any( feature="timer", feature="tokio_timer" )) ]
+	shutdown_aborts            => shutdown_abort              ,
+	bounded                    => bounded_backpressure        ,
+	cancel_scope_cancel        => cancel_scope                ,
+	cancel_scope_cancel_child  => cancel_scope_child          ,
+	cancel_scope_keeps_id      => cancel_scope_task_id        ,
+	tracked_keeps_id           => tracked_task_id             ,
+	metered                    => metered_counts              ,
+	watchdog                   => watchdog_slow_poll          ,
+	catch_unwind               => catch_unwind_hook           ,
+	mock_timer_advance         => mock_timer                  ,
+	#[ cfg(any( feature="timer", feature="tokio_timer" )) ]
+	run_unboxed_timeout        => unboxed_timeout             ,
+}
+
+
+
 // pass an TokioCt to a function that requires a Timer.
 //
 #[ cfg(any( feature="timer", feature="tokio_timer" )) ]
@@ -623,3 +700,66 @@ fn no_tokio_io()
 
 	exec.block_on( test );
 }
+
+
+
+// JoinHandle::try_join returns JoinError::Cancelled when the executor is dropped.
+//
+#[ test ]
+//
+fn join_handle_try_join_cancelled()
+{
+	let exec   = TokioCt::new().expect( "create tokio current thread" );
+	let handle = exec.spawn_handle( futures::future::pending::<()>() ).expect( "spawn" );
+
+	drop( exec );
+
+	let err = block_on( handle.try_join() ).expect_err( "task was canceled" );
+
+	assert!( err.is_cancelled() );
+}
+
+
+
+// Tokio ids are displayed with a prefix, so they can't be mistaken for ids generated by the crate.
+//
+#[ test ]
+//
+fn task_id_display()
+{
+	let exec   = TokioCt::new().expect( "create tokio current thread" );
+	let handle = exec.spawn_handle( async {} ).expect( "spawn" );
+
+	assert!( handle.id().to_string().starts_with( "tokio-" ) );
+
+	exec.block_on( handle );
+}
+
+
+
+helper_tests!
+{
+	|helper| helper( TokioCt::new().expect( "create tokio current thread" ) );
+
+	block_on_trait       => block_on_spawn_handle       ,
+	block_on_trait_local => block_on_spawn_handle_local ,
+}
+
+
+
+// Spawning after the runtime has shut down returns SpawnError::shutdown.
+//
+#[ test ]
+//
+fn spawn_after_runtime_shutdown()
+{
+	let rt   = tokio::runtime::Builder::new_current_thread().build().expect( "create tokio runtime" );
+	let exec = TokioCt::try_from( rt.handle().clone() ).expect( "create from handle" );
+
+	drop( rt );
+
+	assert!( exec.spawn             ( async {}            ).expect_err( "spawn"              ).is_shutdown() );
+	assert!( exec.spawn_local       ( async {}            ).expect_err( "spawn_local"        ).is_shutdown() );
+	assert!( exec.spawn_handle      ( async {}            ).expect_err( "spawn_handle"       ).is_shutdown() );
+	assert!( exec.spawn_handle_local( async { Rc::new(()) } ).expect_err( "spawn_handle_local" ).is_shutdown() );
+}